@@ -19,26 +19,25 @@ pub fn benchtest(n: usize) {
         {
             let _ = Search::new()
             .query(
-                Query::new()
-                    .bool(
-                        Bool::new()
-                            .must(
-                                clause!(
-                                    Match,
-                                    "event.action",
-                                    QueryValue::Text("logged-in".to_owned())
-                                )
+                Query::Bool(
+                    Bool::new()
+                        .must(
+                            clause!(
+                                Match,
+                                "event.action",
+                                QueryValue::Text("logged-in".to_owned())
                             )
-                            .filter(
-                                clause!(
-                                    Range,
-                                    "@timestamp",
-                                    gte = RangeValue::Date("now-1h/H".to_owned()),
-                                    lte = RangeValue::Date("now/H".to_owned()),
-                                    format = "HH:mm:ss yyyy/MM/DD"
-                                )
+                        )
+                        .filter(
+                            clause!(
+                                Range,
+                                "@timestamp",
+                                gte = RangeValue::Date("now-1h/H".to_owned()),
+                                lte = RangeValue::Date("now/H".to_owned()),
+                                format = "HH:mm:ss yyyy/MM/DD"
                             )
-                    )
+                        )
+                )
             )
             .sort(
                 Sort::new()
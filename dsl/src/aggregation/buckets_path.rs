@@ -0,0 +1,117 @@
+use std::borrow::Cow;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+use super::Aggregations;
+
+/// The path from a pipeline aggregation to the values it works on, e.g.
+/// `sales_per_month>sales` or `the_stats.avg`, written
+/// `AGG_NAME[>AGG_NAME]*[.METRIC]`
+///
+/// The first aggregation is a sibling of the pipeline aggregation, for the
+/// pipelines inside a multi-bucket aggregation, e.g. a `derivative` inside a
+/// `date_histogram`, the siblings being the other sub-aggregations of that
+/// parent. `_count`, `_key` and `_bucket_count` can be used in place of an
+/// aggregation name.
+///
+/// Reference: [buckets_path syntax](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline.html#buckets-path-syntax)
+///
+/// # Example
+/// ```
+/// use dsl::aggregation::buckets_path::BucketsPath;
+///
+/// let path = BucketsPath::new("sales_per_month").agg("sales_stats").metric("avg");
+///
+/// assert_eq!(path.to_string(), "sales_per_month>sales_stats.avg");
+/// assert_eq!("sales_per_month>sales_stats.avg".parse::<BucketsPath>().unwrap(), path);
+/// ```
+#[derive(Clone, Debug, PartialEq, SerializeDisplay, DeserializeFromStr)]
+pub struct BucketsPath {
+    aggs: Vec<Cow<'static, str>>,
+    metric: Option<Cow<'static, str>>,
+}
+
+const SPECIAL_PATHS: [&str; 3] = ["_count", "_key", "_bucket_count"];
+
+impl BucketsPath {
+    pub fn new(agg: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            aggs: vec![agg.into()],
+            metric: None,
+        }
+    }
+
+    /// Descends into a sub-aggregation of the last aggregation of the path
+    pub fn agg(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.aggs.push(v.into());
+
+        self
+    }
+
+    /// The metric of a multi-value metric aggregation, e.g. `avg` of a `stats` aggregation
+    pub fn metric(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.metric = Some(v.into());
+
+        self
+    }
+
+    /// Resolves the aggregations of the path, starting from the siblings of the
+    /// pipeline aggregation, returns the first one missing
+    pub(super) fn validate(&self, siblings: &Aggregations) -> Result<(), Cow<'static, str>> {
+        let mut aggs = Some(siblings);
+
+        for (i, name) in self.aggs.iter().enumerate() {
+            // Strips the bucket key of a multi-bucket aggregation, e.g. `sale_type['hat']`
+            let agg_name = name.split('[').next().unwrap_or_default();
+
+            if i == self.aggs.len() - 1 && SPECIAL_PATHS.contains(&agg_name) {
+                break;
+            }
+
+            aggs = aggs.and_then(|aggs| aggs.get(agg_name))
+                .ok_or_else(|| name.clone())?
+                .aggs();
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for BucketsPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.aggs.join(">"))?;
+
+        if let Some(metric) = &self.metric {
+            write!(f, ".{}", metric)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for BucketsPath {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut aggs: Vec<Cow<'static, str>> = s.split('>').map(|agg| agg.to_owned().into()).collect();
+        let mut metric = None;
+
+        // Only the last element may hold a metric, unless the dot is part of a bucket key
+        if let Some(last) = aggs.last_mut() {
+            if let Some(i) = last.rfind('.').filter(|i| !last[*i..].contains(']')) {
+                metric = Some(last[i + 1..].to_owned().into());
+                *last = last[..i].to_owned().into();
+            }
+        }
+
+        if aggs.iter().any(|agg| agg.is_empty()) || metric.as_deref() == Some("") {
+            return Err(format!("invalid buckets_path `{}`", s));
+        }
+
+        Ok(Self {
+            aggs,
+            metric,
+        })
+    }
+}
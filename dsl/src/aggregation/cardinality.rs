@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::values_source::{impl_values_source, ValuesSource};
+
+/// Approximate count of distinct values
+///
+/// Reference: [Cardinality aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-metrics-cardinality-aggregation.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Cardinality {
+    #[serde(flatten)]
+    source: ValuesSource,
+    precision_threshold: Option<u32>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Cardinality {
+    pub fn new() -> Self {
+        Self {
+            source: ValuesSource::new(),
+            precision_threshold: None,
+            meta: None,
+        }
+    }
+
+    /// Count below which the counts are expected to be close to accurate, defaults to 3000, maximum 40000
+    pub fn precision_threshold(mut self, v: u32) -> Self {
+        self.precision_threshold = Some(v);
+
+        self
+    }
+}
+
+impl_values_source!(Cardinality);
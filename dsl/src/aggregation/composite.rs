@@ -0,0 +1,238 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    field::{Field, FieldEntry},
+    sort::Order,
+    types::{number::Number, time::TimeValue},
+};
+
+use super::{
+    histogram::{CalendarInterval, DateInterval},
+    Aggregations,
+};
+
+/// One bucket per combination of the values of its sources, paginated
+/// with `after` to stream all the buckets
+///
+/// Reference: [Composite aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-composite-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     aggregation::{
+///         composite::{Composite, CompositeSource, DateHistogramSource, TermsSource},
+///         histogram::CalendarInterval,
+///         Aggregation,
+///     },
+///     search::Search,
+///     sort::Order,
+/// };
+///
+/// let search = Search::new().agg(
+///     "my_buckets",
+///     Aggregation::Composite(
+///         Composite::new()
+///             .source("date", CompositeSource::DateHistogram(DateHistogramSource::calendar("timestamp", CalendarInterval::Day)))
+///             .source("product", CompositeSource::Terms(TermsSource::new("product").order(Order::Desc).missing_bucket(true)))
+///             .size(100)
+///     )
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Composite {
+    sources: Vec<FieldEntry<CompositeSource>>,
+    size: Option<u32>,
+    after: Option<serde_json::Map<String, serde_json::Value>>,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Composite {
+    pub fn new() -> Self {
+        Self {
+            sources: vec![],
+            size: None,
+            after: None,
+            aggs: None,
+            meta: None,
+        }
+    }
+
+    /// Adds a named source, the buckets being sorted by the sources in the order they were added
+    pub fn source(mut self, name: impl Into<Field>, source: CompositeSource) -> Self {
+        self.sources.push(FieldEntry::new(name, source));
+
+        self
+    }
+
+    /// Number of buckets per page, defaults to 10
+    pub fn size(mut self, v: u32) -> Self {
+        self.size = Some(v);
+
+        self
+    }
+
+    /// Returns the buckets following this key, the `after_key` of the previous page
+    pub fn after(mut self, after_key: serde_json::Map<String, serde_json::Value>) -> Self {
+        self.after = Some(after_key);
+
+        self
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompositeSource {
+    Terms(TermsSource),
+    Histogram(HistogramSource),
+    DateHistogram(DateHistogramSource),
+    GeotileGrid(GeotileGridSource),
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SourceOptions {
+    field: Field,
+    order: Option<Order>,
+    missing_bucket: Option<bool>,
+}
+
+impl SourceOptions {
+    fn new(field: impl Into<Field>) -> Self {
+        Self {
+            field: field.into(),
+            order: None,
+            missing_bucket: None,
+        }
+    }
+}
+
+macro_rules! impl_source_options {
+    ($($source:ident),*) => {
+        $(
+            impl $source {
+                /// Defaults to `asc`
+                pub fn order(mut self, v: Order) -> Self {
+                    self.options.order = Some(v);
+
+                    self
+                }
+
+                /// Whether to add a `null` bucket for the documents without a value
+                pub fn missing_bucket(mut self, v: bool) -> Self {
+                    self.options.missing_bucket = Some(v);
+
+                    self
+                }
+            }
+        )*
+    };
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TermsSource {
+    #[serde(flatten)]
+    options: SourceOptions,
+}
+
+impl TermsSource {
+    pub fn new(field: impl Into<Field>) -> Self {
+        Self {
+            options: SourceOptions::new(field),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HistogramSource {
+    #[serde(flatten)]
+    options: SourceOptions,
+    interval: Number,
+}
+
+impl HistogramSource {
+    pub fn new(field: impl Into<Field>, interval: impl Into<Number>) -> Self {
+        Self {
+            options: SourceOptions::new(field),
+            interval: interval.into(),
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DateHistogramSource {
+    #[serde(flatten)]
+    options: SourceOptions,
+    #[serde(flatten)]
+    interval: DateInterval,
+    format: Option<Cow<'static, str>>,
+    time_zone: Option<Cow<'static, str>>,
+}
+
+impl DateHistogramSource {
+    pub fn calendar(field: impl Into<Field>, interval: CalendarInterval) -> Self {
+        Self::with(field, DateInterval::Calendar(interval))
+    }
+
+    pub fn fixed(field: impl Into<Field>, interval: TimeValue) -> Self {
+        Self::with(field, DateInterval::Fixed(interval))
+    }
+
+    fn with(field: impl Into<Field>, interval: DateInterval) -> Self {
+        Self {
+            options: SourceOptions::new(field),
+            interval,
+            format: None,
+            time_zone: None,
+        }
+    }
+
+    /// The format of the keys, which are milliseconds since the epoch by default
+    pub fn format(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.format = Some(v.into());
+
+        self
+    }
+
+    pub fn time_zone(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.time_zone = Some(v.into());
+
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GeotileGridSource {
+    #[serde(flatten)]
+    options: SourceOptions,
+    precision: Option<u8>,
+}
+
+impl GeotileGridSource {
+    pub fn new(field: impl Into<Field>) -> Self {
+        Self {
+            options: SourceOptions::new(field),
+            precision: None,
+        }
+    }
+
+    /// Zoom level of the tiles, from 0 to 29, defaults to 7
+    pub fn precision(mut self, v: u8) -> Self {
+        self.precision = Some(v);
+
+        self
+    }
+}
+
+impl_source_options!(TermsSource, HistogramSource, DateHistogramSource, GeotileGridSource);
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::values_source::{impl_values_source, ValuesSource};
+
+/// Reference: [Extended stats aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-metrics-extendedstats-aggregation.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExtendedStats {
+    #[serde(flatten)]
+    source: ValuesSource,
+    sigma: Option<f64>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl ExtendedStats {
+    pub fn new() -> Self {
+        Self {
+            source: ValuesSource::new(),
+            sigma: None,
+            meta: None,
+        }
+    }
+
+    /// Number of standard deviations of the `std_deviation_bounds`, defaults to 2
+    pub fn sigma(mut self, v: f64) -> Self {
+        self.sigma = Some(v);
+
+        self
+    }
+}
+
+impl_values_source!(ExtendedStats);
@@ -0,0 +1,195 @@
+use std::borrow::Cow;
+use std::fmt;
+use serde::{
+    de::{MapAccess, Visitor},
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
+use serde_with::skip_serializing_none;
+use crate::query::Query;
+
+use super::Aggregations;
+
+/// A single bucket of the documents matching a query
+///
+/// Reference: [Filter aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-filter-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     aggregation::{filter::Filter, metric::Avg, Aggregation},
+///     clause,
+/// };
+///
+/// let agg = Aggregation::Filter(
+///     Filter::new(clause!(Term, "type", "t-shirt"))
+///         .agg("avg_price", Aggregation::Avg(Avg::new().field("price")))
+/// );
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Filter {
+    query: Query,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Filter {
+    pub fn new(query: Query) -> Self {
+        Self {
+            query,
+            aggs: None,
+            meta: None,
+        }
+    }
+}
+
+/// One bucket per query, the buckets being named when the filters are keyed
+///
+/// Reference: [Filters aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-filters-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     aggregation::{filter::Filters, Aggregation},
+///     clause,
+///     query::QueryValue,
+/// };
+///
+/// let agg = Aggregation::Filters(
+///     Filters::new()
+///         .filter("errors", clause!(Match, "body", QueryValue::from("error")))
+///         .filter("warnings", clause!(Match, "body", QueryValue::from("warning")))
+///         .other_bucket_key("other_messages")
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Filters {
+    filters: FiltersQueries,
+    other_bucket: Option<bool>,
+    other_bucket_key: Option<Cow<'static, str>>,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Filters {
+    /// Keyed filters, added with `filter` and serialized in the order they were added
+    pub fn new() -> Self {
+        Self::with(FiltersQueries::Keyed(KeyedQueries(vec![])))
+    }
+
+    /// Anonymous filters, the buckets being returned in the order of the queries
+    pub fn anonymous(queries: impl IntoIterator<Item = Query>) -> Self {
+        Self::with(FiltersQueries::Anonymous(queries.into_iter().collect()))
+    }
+
+    fn with(filters: FiltersQueries) -> Self {
+        Self {
+            filters,
+            other_bucket: None,
+            other_bucket_key: None,
+            aggs: None,
+            meta: None,
+        }
+    }
+
+    /// Adds a named filter, replacing the one of the same name if any
+    ///
+    /// # Panics
+    ///
+    /// Panics on anonymous filters, which take all their queries in
+    /// [`Filters::anonymous`]
+    pub fn filter(mut self, name: impl Into<Cow<'static, str>>, query: Query) -> Self {
+        let filters = match &mut self.filters {
+            FiltersQueries::Keyed(KeyedQueries(filters)) => filters,
+            FiltersQueries::Anonymous(_) => panic!("can't add the named filter `{}` to anonymous filters", name.into()),
+        };
+        let name = name.into();
+
+        match filters.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, q)) => *q = query,
+            None => filters.push((name, query)),
+        }
+
+        self
+    }
+
+    /// Whether to add a bucket for the documents matching none of the filters
+    pub fn other_bucket(mut self, v: bool) -> Self {
+        self.other_bucket = Some(v);
+
+        self
+    }
+
+    /// The name of the other bucket, defaults to `_other_`, implies `other_bucket`
+    pub fn other_bucket_key(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.other_bucket_key = Some(v.into());
+
+        self
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum FiltersQueries {
+    Keyed(KeyedQueries),
+    Anonymous(Vec<Query>),
+}
+
+/// Named queries, serialized as `{"<name>": {...}, ...}` in the order they were added
+#[derive(Clone, Debug)]
+struct KeyedQueries(Vec<(Cow<'static, str>, Query)>);
+
+impl Serialize for KeyedQueries {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, query) in &self.0 {
+            m.serialize_entry(name, query)?;
+        }
+        m.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyedQueries {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        struct KeyedQueriesVisitor;
+
+        impl<'de> Visitor<'de> for KeyedQueriesVisitor {
+            type Value = KeyedQueries;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map of named queries")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut queries = Vec::with_capacity(map.size_hint().unwrap_or(0));
+
+                while let Some(entry) = map.next_entry::<Cow<'static, str>, Query>()? {
+                    queries.push(entry);
+                }
+
+                Ok(KeyedQueries(queries))
+            }
+        }
+
+        deserializer.deserialize_map(KeyedQueriesVisitor)
+    }
+}
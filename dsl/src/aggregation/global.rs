@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use super::Aggregations;
+
+/// A single bucket of all the documents of the searched indices, regardless
+/// of the query, only valid at the top level of the aggregations
+///
+/// Reference: [Global aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-global-aggregation.html)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Global {
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Global {
+    pub fn new() -> Self {
+        Self {
+            aggs: None,
+            meta: None,
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+use macros::DisplayCase;
+use serde::{Deserialize, Serialize};
+use serde_with::{skip_serializing_none, DeserializeFromStr, SerializeDisplay};
+use crate::{
+    field::{Field, FieldEntry},
+    query::QueryValue,
+    sort::Order,
+    types::{number::Number, time::TimeValue},
+};
+
+use super::{
+    values_source::{impl_values_source, ValuesSource},
+    Aggregations,
+};
+
+/// One bucket per `interval` wide range of numeric values
+///
+/// Reference: [Histogram aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-histogram-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::aggregation::{
+///     histogram::{ExtendedBounds, Histogram},
+///     Aggregation,
+/// };
+///
+/// let agg = Aggregation::Histogram(
+///     Histogram::new(50)
+///         .field("price")
+///         .min_doc_count(0)
+///         .extended_bounds(ExtendedBounds::new(0, 500))
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Histogram {
+    #[serde(flatten)]
+    source: ValuesSource,
+    interval: Number,
+    offset: Option<Number>,
+    min_doc_count: Option<u64>,
+    extended_bounds: Option<ExtendedBounds<Number>>,
+    hard_bounds: Option<ExtendedBounds<Number>>,
+    #[serde(default, deserialize_with = "crate::de::one_or_many")]
+    order: Option<Vec<FieldEntry<Order>>>,
+    keyed: Option<bool>,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Histogram {
+    pub fn new(interval: impl Into<Number>) -> Self {
+        Self {
+            source: ValuesSource::new(),
+            interval: interval.into(),
+            offset: None,
+            min_doc_count: None,
+            extended_bounds: None,
+            hard_bounds: None,
+            order: None,
+            keyed: None,
+            aggs: None,
+            meta: None,
+        }
+    }
+
+    /// Shifts the bucket boundaries, which start at 0 by default
+    pub fn offset(mut self, v: impl Into<Number>) -> Self {
+        self.offset = Some(v.into());
+
+        self
+    }
+
+    /// Set to 0 to return the empty buckets
+    pub fn min_doc_count(mut self, v: u64) -> Self {
+        self.min_doc_count = Some(v);
+
+        self
+    }
+
+    /// Returns buckets up to these bounds, even beyond the values, requires `min_doc_count` 0
+    pub fn extended_bounds(mut self, v: ExtendedBounds<Number>) -> Self {
+        self.extended_bounds = Some(v);
+
+        self
+    }
+
+    /// Only returns buckets within these bounds
+    pub fn hard_bounds(mut self, v: ExtendedBounds<Number>) -> Self {
+        self.hard_bounds = Some(v);
+
+        self
+    }
+
+    /// Orders the buckets by `_count`, `_key` or the path of a sub-aggregation,
+    /// the following calls break the ties of the previous ones
+    pub fn order(mut self, key: impl Into<Field>, order: Order) -> Self {
+        self.order.get_or_insert_with(Vec::new).push(FieldEntry::new(key, order));
+
+        self
+    }
+
+    pub fn keyed(mut self, v: bool) -> Self {
+        self.keyed = Some(v);
+
+        self
+    }
+}
+
+/// One bucket per calendar or fixed interval of dates
+///
+/// Reference: [Date histogram aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-datehistogram-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     aggregation::{
+///         histogram::{CalendarInterval, DateHistogram},
+///         metric::Sum,
+///         Aggregation,
+///     },
+///     types::time::{TimeUnit, TimeValue},
+/// };
+///
+/// let agg = Aggregation::DateHistogram(
+///     DateHistogram::calendar(CalendarInterval::Month)
+///         .field("date")
+///         .time_zone("-01:00")
+///         .agg("sales", Aggregation::Sum(Sum::new().field("price")))
+/// );
+///
+/// let agg = Aggregation::DateHistogram(DateHistogram::fixed(TimeValue::new(30, TimeUnit::Days)).field("date"));
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DateHistogram {
+    #[serde(flatten)]
+    source: ValuesSource,
+    #[serde(flatten)]
+    interval: DateInterval,
+    format: Option<Cow<'static, str>>,
+    time_zone: Option<Cow<'static, str>>,
+    offset: Option<Cow<'static, str>>,
+    min_doc_count: Option<u64>,
+    extended_bounds: Option<ExtendedBounds<QueryValue>>,
+    hard_bounds: Option<ExtendedBounds<QueryValue>>,
+    #[serde(default, deserialize_with = "crate::de::one_or_many")]
+    order: Option<Vec<FieldEntry<Order>>>,
+    keyed: Option<bool>,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl DateHistogram {
+    /// Calendar-aware intervals, e.g. a month lasting from 28 to 31 days
+    pub fn calendar(interval: CalendarInterval) -> Self {
+        Self::with(DateInterval::Calendar(interval))
+    }
+
+    /// Intervals of a fixed number of SI units
+    pub fn fixed(interval: TimeValue) -> Self {
+        Self::with(DateInterval::Fixed(interval))
+    }
+
+    fn with(interval: DateInterval) -> Self {
+        Self {
+            source: ValuesSource::new(),
+            interval,
+            format: None,
+            time_zone: None,
+            offset: None,
+            min_doc_count: None,
+            extended_bounds: None,
+            hard_bounds: None,
+            order: None,
+            keyed: None,
+            aggs: None,
+            meta: None,
+        }
+    }
+
+    /// The format of the keys of the response
+    pub fn format(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.format = Some(v.into());
+
+        self
+    }
+
+    /// An ISO 8601 UTC offset, e.g. `-01:00`, or a time zone ID, e.g. `America/Los_Angeles`
+    pub fn time_zone(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.time_zone = Some(v.into());
+
+        self
+    }
+
+    /// Shifts the start of the buckets, e.g. `+6h`
+    pub fn offset(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.offset = Some(v.into());
+
+        self
+    }
+
+    /// Set to 0 to return the empty buckets
+    pub fn min_doc_count(mut self, v: u64) -> Self {
+        self.min_doc_count = Some(v);
+
+        self
+    }
+
+    /// Returns buckets up to these bounds, even beyond the values, requires `min_doc_count` 0
+    pub fn extended_bounds(mut self, v: ExtendedBounds<QueryValue>) -> Self {
+        self.extended_bounds = Some(v);
+
+        self
+    }
+
+    /// Only returns buckets within these bounds
+    pub fn hard_bounds(mut self, v: ExtendedBounds<QueryValue>) -> Self {
+        self.hard_bounds = Some(v);
+
+        self
+    }
+
+    /// Orders the buckets by `_count`, `_key` or the path of a sub-aggregation,
+    /// the following calls break the ties of the previous ones
+    pub fn order(mut self, key: impl Into<Field>, order: Order) -> Self {
+        self.order.get_or_insert_with(Vec::new).push(FieldEntry::new(key, order));
+
+        self
+    }
+
+    pub fn keyed(mut self, v: bool) -> Self {
+        self.keyed = Some(v);
+
+        self
+    }
+}
+
+impl_values_source!(Histogram, DateHistogram);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(super) enum DateInterval {
+    #[serde(rename = "calendar_interval")]
+    Calendar(CalendarInterval),
+    #[serde(rename = "fixed_interval")]
+    Fixed(TimeValue),
+}
+
+/// Serialized by its name, e.g. `month`, also parsed from its `1x`
+/// shorthand, e.g. `1M`
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, DisplayCase, SerializeDisplay, DeserializeFromStr)]
+#[display_case(case = "lowercase")]
+pub enum CalendarInterval {
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl FromStr for CalendarInterval {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The shorthands are case sensitive, `1m` being a minute and `1M` a month
+        let interval = match s {
+            "1m" => Self::Minute,
+            "1h" => Self::Hour,
+            "1d" => Self::Day,
+            "1w" => Self::Week,
+            "1M" => Self::Month,
+            "1q" => Self::Quarter,
+            "1y" => Self::Year,
+            _ => match s.to_ascii_lowercase().as_str() {
+                "minute" => Self::Minute,
+                "hour" => Self::Hour,
+                "day" => Self::Day,
+                "week" => Self::Week,
+                "month" => Self::Month,
+                "quarter" => Self::Quarter,
+                "year" => Self::Year,
+                _ => return Err(format!("unknown variant `{}`", s)),
+            },
+        };
+
+        Ok(interval)
+    }
+}
+
+/// The `min` and `max` bounds of a histogram
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExtendedBounds<T> {
+    min: T,
+    max: T,
+}
+
+impl<T> ExtendedBounds<T> {
+    pub fn new(min: impl Into<T>, max: impl Into<T>) -> Self {
+        Self {
+            min: min.into(),
+            max: max.into(),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+
+use super::Aggregations;
+
+/// A single bucket of the child documents of `type` of the matching parent documents
+///
+/// Reference: [Children aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-children-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::aggregation::{join::Children, terms::Terms, Aggregation};
+///
+/// let agg = Aggregation::Children(
+///     Children::new("answer").agg("top_names", Aggregation::Terms(Terms::new().field("owner.display_name.keyword")))
+/// );
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Children {
+    r#type: Cow<'static, str>,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Children {
+    pub fn new(r#type: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            r#type: r#type.into(),
+            aggs: None,
+            meta: None,
+        }
+    }
+}
+
+/// A single bucket of the parent documents of the matching child documents of `type`
+///
+/// Reference: [Parent aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-parent-aggregation.html)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Parent {
+    r#type: Cow<'static, str>,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Parent {
+    /// `type` is the child relation the parents are joined from
+    pub fn new(r#type: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            r#type: r#type.into(),
+            aggs: None,
+            meta: None,
+        }
+    }
+}
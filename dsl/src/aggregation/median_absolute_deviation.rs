@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::values_source::{impl_values_source, ValuesSource};
+
+/// Reference: [Median absolute deviation aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-metrics-median-absolute-deviation-aggregation.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MedianAbsoluteDeviation {
+    #[serde(flatten)]
+    source: ValuesSource,
+    compression: Option<f64>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl MedianAbsoluteDeviation {
+    pub fn new() -> Self {
+        Self {
+            source: ValuesSource::new(),
+            compression: None,
+            meta: None,
+        }
+    }
+
+    /// Accuracy of the TDigest sketch against its memory usage, defaults to 1000
+    pub fn compression(mut self, v: f64) -> Self {
+        self.compression = Some(v);
+
+        self
+    }
+}
+
+impl_values_source!(MedianAbsoluteDeviation);
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::values_source::{impl_values_source, ValuesSource};
+
+macro_rules! declare_metric {
+    ($($(#[$meta:meta])* $agg:ident,)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Clone, Debug, Serialize, Deserialize)]
+            #[serde(deny_unknown_fields)]
+            pub struct $agg {
+                #[serde(flatten)]
+                source: ValuesSource,
+                #[serde(skip)]
+                pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+            }
+
+            impl $agg {
+                pub fn new() -> Self {
+                    Self {
+                        source: ValuesSource::new(),
+                        meta: None,
+                    }
+                }
+            }
+
+            impl_values_source!($agg);
+        )*
+    };
+}
+
+declare_metric! {
+    /// Reference: [Avg aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-metrics-avg-aggregation.html)
+    ///
+    /// # Example
+    /// ```
+    /// use dsl::{
+    ///     aggregation::{metric::Avg, Aggregation},
+    ///     search::Search,
+    /// };
+    ///
+    /// let search = Search::new().agg("avg_grade", Aggregation::Avg(Avg::new().field("grade").missing(10)));
+    /// ```
+    Avg,
+    /// Reference: [Max aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-metrics-max-aggregation.html)
+    Max,
+    /// Reference: [Min aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-metrics-min-aggregation.html)
+    Min,
+    /// Reference: [Stats aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-metrics-stats-aggregation.html)
+    Stats,
+    /// Reference: [Sum aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-metrics-sum-aggregation.html)
+    Sum,
+    /// Reference: [Value count aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-metrics-valuecount-aggregation.html)
+    ValueCount,
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use crate::field::Field;
+
+use super::Aggregations;
+
+/// A single bucket of the documents without a value for `field`
+///
+/// Reference: [Missing aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-missing-aggregation.html)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Missing {
+    field: Field,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Missing {
+    pub fn new(field: impl Into<Field>) -> Self {
+        Self {
+            field: field.into(),
+            aggs: None,
+            meta: None,
+        }
+    }
+}
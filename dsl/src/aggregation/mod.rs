@@ -0,0 +1,454 @@
+pub mod buckets_path;
+pub mod cardinality;
+pub mod composite;
+pub mod extended_stats;
+pub mod filter;
+pub mod global;
+pub mod histogram;
+pub mod join;
+pub mod median_absolute_deviation;
+pub mod metric;
+pub mod missing;
+pub mod multi_terms;
+pub mod nested;
+pub mod percentiles;
+pub mod pipeline;
+pub mod range;
+pub mod significant_terms;
+pub mod terms;
+pub mod top_hits;
+pub mod values_source;
+pub mod weighted_avg;
+
+use std::borrow::Cow;
+use std::fmt;
+use serde::{
+    de::{self, MapAccess, Visitor},
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
+use serde_with::skip_serializing_none;
+use buckets_path::BucketsPath;
+use cardinality::Cardinality;
+use composite::Composite;
+use extended_stats::ExtendedStats;
+use filter::{Filter, Filters};
+use global::Global;
+use histogram::{DateHistogram, Histogram};
+use join::{Children, Parent};
+use median_absolute_deviation::MedianAbsoluteDeviation;
+use metric::{Avg, Max, Min, Stats, Sum, ValueCount};
+use missing::Missing;
+use multi_terms::MultiTerms;
+use nested::{Nested, ReverseNested};
+use percentiles::{PercentileRanks, Percentiles};
+use pipeline::{
+    AvgBucket,
+    BucketScript,
+    BucketSelector,
+    BucketSort,
+    CumulativeSum,
+    Derivative,
+    MaxBucket,
+    MinBucket,
+    MovingFn,
+    SerialDiff,
+    StatsBucket,
+    SumBucket,
+};
+use range::{DateRange, Range};
+use significant_terms::SignificantTerms;
+use terms::Terms;
+use top_hits::TopHits;
+use weighted_avg::WeightedAvg;
+
+/// Named aggregations, serialized as `{"<name>": {...}, ...}` in the order they were added
+///
+/// Reference: [Aggregations](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations.html)
+#[derive(Clone, Debug)]
+pub struct Aggregations {
+    aggs: Vec<(Cow<'static, str>, Aggregation)>,
+}
+
+impl Aggregations {
+    pub fn new() -> Self {
+        Self {
+            aggs: vec![],
+        }
+    }
+
+    /// Adds an aggregation, replacing the one of the same name if any
+    pub fn agg(mut self, name: impl Into<Cow<'static, str>>, agg: Aggregation) -> Self {
+        let name = name.into();
+
+        match self.aggs.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, a)) => *a = agg,
+            None => self.aggs.push((name, agg)),
+        }
+
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Aggregation> {
+        self.aggs.iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, agg)| agg)
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Aggregation> {
+        self.aggs.iter_mut()
+            .find(|(n, _)| n == name)
+            .map(|(_, agg)| agg)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Aggregation)> {
+        self.aggs.iter().map(|(name, agg)| (name.as_ref(), agg))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.aggs.is_empty()
+    }
+
+    /// Checks that the `buckets_path` of the pipeline aggregations of the tree
+    /// lead to existing aggregations
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, agg) in &self.aggs {
+            for path in agg.buckets_paths() {
+                path.validate(self).map_err(|missing| {
+                    format!("the buckets_path `{}` of `{}` refers to the missing aggregation `{}`", path, name, missing)
+                })?;
+            }
+
+            if let Some(aggs) = agg.aggs() {
+                aggs.validate()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Serialize for Aggregations {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(Some(self.aggs.len()))?;
+        for (name, agg) in &self.aggs {
+            m.serialize_entry(name, agg)?;
+        }
+        m.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Aggregations {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        struct AggregationsVisitor;
+
+        impl<'de> Visitor<'de> for AggregationsVisitor {
+            type Value = Aggregations;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map of named aggregations")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut aggs = Vec::with_capacity(map.size_hint().unwrap_or(0));
+
+                while let Some((name, agg)) = map.next_entry::<Cow<'static, str>, Aggregation>()? {
+                    aggs.push((name, agg));
+                }
+
+                Ok(Aggregations {
+                    aggs,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(AggregationsVisitor)
+    }
+}
+
+macro_rules! declare_aggregation {
+    (
+        metrics: [$($metric:ident),*],
+        pipelines: [$($pipeline:ident),*],
+        buckets: [$($bucket:ident),*]
+    ) => {
+        /// An aggregation, serialized as `{"<aggregation_type>": {...}}`
+        ///
+        /// Bucket aggregations hold named sub-aggregations, added with their
+        /// `agg` method and serialized next to the aggregation type as
+        /// `{"<aggregation_type>": {...}, "aggs": {...}}`. Any aggregation may
+        /// also carry a `meta` object, returned as is in the response.
+        #[derive(Clone, Debug)]
+        pub enum Aggregation {
+            $($metric($metric),)*
+            $($pipeline($pipeline),)*
+            $($bucket($bucket),)*
+        }
+
+        impl Aggregation {
+            /// The sub-aggregations, always `None` for metric and pipeline aggregations
+            pub fn aggs(&self) -> Option<&Aggregations> {
+                match self {
+                    $(Self::$metric(_) => None,)*
+                    $(Self::$pipeline(_) => None,)*
+                    $(Self::$bucket(v) => v.aggs.as_ref(),)*
+                }
+            }
+
+            pub fn meta(&self) -> Option<&serde_json::Map<String, serde_json::Value>> {
+                match self {
+                    $(Self::$metric(v) => v.meta.as_ref(),)*
+                    $(Self::$pipeline(v) => v.meta.as_ref(),)*
+                    $(Self::$bucket(v) => v.meta.as_ref(),)*
+                }
+            }
+
+            fn buckets_paths(&self) -> Vec<&BucketsPath> {
+                match self {
+                    $(Self::$metric(_) => vec![],)*
+                    $(Self::$pipeline(v) => v.buckets_paths(),)*
+                    $(Self::$bucket(_) => vec![],)*
+                }
+            }
+        }
+
+        $(
+            impl $metric {
+                /// Adds a `meta` object, returned as is in the response
+                pub fn meta(mut self, v: serde_json::Map<String, serde_json::Value>) -> Self {
+                    self.meta = Some(v);
+
+                    self
+                }
+            }
+        )*
+
+        $(
+            impl $pipeline {
+                /// Adds a `meta` object, returned as is in the response
+                pub fn meta(mut self, v: serde_json::Map<String, serde_json::Value>) -> Self {
+                    self.meta = Some(v);
+
+                    self
+                }
+            }
+        )*
+
+        $(
+            impl $bucket {
+                /// Adds a named sub-aggregation
+                pub fn agg(mut self, name: impl Into<Cow<'static, str>>, agg: Aggregation) -> Self {
+                    self.aggs = Some(self.aggs.take().unwrap_or_else(Aggregations::new).agg(name, agg));
+
+                    self
+                }
+
+                /// Adds a `meta` object, returned as is in the response
+                pub fn meta(mut self, v: serde_json::Map<String, serde_json::Value>) -> Self {
+                    self.meta = Some(v);
+
+                    self
+                }
+            }
+        )*
+
+        #[derive(Serialize)]
+        #[serde(rename_all = "snake_case")]
+        enum AggregationRef<'a> {
+            $($metric(&'a $metric),)*
+            $($pipeline(&'a $pipeline),)*
+            $($bucket(&'a $bucket),)*
+        }
+
+        /// The keys of an aggregation, its type, `aggs` and `meta`
+        #[derive(Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        enum AggregationKey {
+            $($metric,)*
+            $($pipeline,)*
+            $($bucket,)*
+            #[serde(alias = "aggregations")]
+            Aggs,
+            Meta,
+        }
+
+        impl Serialize for Aggregation {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer
+            {
+                let kind = match self {
+                    $(Self::$metric(v) => AggregationRef::$metric(v),)*
+                    $(Self::$pipeline(v) => AggregationRef::$pipeline(v),)*
+                    $(Self::$bucket(v) => AggregationRef::$bucket(v),)*
+                };
+
+                AggregationRepr {
+                    kind,
+                    meta: self.meta(),
+                    aggs: self.aggs(),
+                }
+                .serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Aggregation {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>
+            {
+                struct AggregationVisitor;
+
+                impl<'de> Visitor<'de> for AggregationVisitor {
+                    type Value = Aggregation;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str("a map with a single aggregation type and optional `aggs` and `meta`")
+                    }
+
+                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: MapAccess<'de>,
+                    {
+                        let mut agg = None;
+                        let mut aggs = None;
+                        let mut meta = None;
+
+                        while let Some(key) = map.next_key::<AggregationKey>()? {
+                            match key {
+                                AggregationKey::Aggs => {
+                                    if aggs.is_some() {
+                                        return Err(de::Error::duplicate_field("aggs"));
+                                    }
+
+                                    aggs = Some(map.next_value::<Aggregations>()?);
+                                },
+                                AggregationKey::Meta => {
+                                    if meta.is_some() {
+                                        return Err(de::Error::duplicate_field("meta"));
+                                    }
+
+                                    meta = Some(map.next_value()?);
+                                },
+                                _ if agg.is_some() => {
+                                    return Err(de::Error::custom("expected a single aggregation type, found more"));
+                                },
+                                $(AggregationKey::$metric => agg = Some(Aggregation::$metric(map.next_value()?)),)*
+                                $(AggregationKey::$pipeline => agg = Some(Aggregation::$pipeline(map.next_value()?)),)*
+                                $(AggregationKey::$bucket => agg = Some(Aggregation::$bucket(map.next_value()?)),)*
+                            }
+                        }
+
+                        let mut agg = agg.ok_or_else(|| de::Error::custom("missing the aggregation type"))?;
+
+                        match &mut agg {
+                            $(
+                                Aggregation::$metric(v) => {
+                                    if aggs.is_some() {
+                                        return Err(de::Error::custom(
+                                            concat!("the `", stringify!($metric), "` aggregation can't hold sub-aggregations")
+                                        ));
+                                    }
+
+                                    v.meta = meta;
+                                },
+                            )*
+                            $(
+                                Aggregation::$pipeline(v) => {
+                                    if aggs.is_some() {
+                                        return Err(de::Error::custom(
+                                            concat!("the `", stringify!($pipeline), "` aggregation can't hold sub-aggregations")
+                                        ));
+                                    }
+
+                                    v.meta = meta;
+                                },
+                            )*
+                            $(
+                                Aggregation::$bucket(v) => {
+                                    v.aggs = aggs;
+                                    v.meta = meta;
+                                },
+                            )*
+                        }
+
+                        Ok(agg)
+                    }
+                }
+
+                deserializer.deserialize_map(AggregationVisitor)
+            }
+        }
+    };
+}
+
+#[skip_serializing_none]
+#[derive(Serialize)]
+struct AggregationRepr<'a, K> {
+    #[serde(flatten)]
+    kind: K,
+    meta: Option<&'a serde_json::Map<String, serde_json::Value>>,
+    aggs: Option<&'a Aggregations>,
+}
+
+declare_aggregation!(
+    metrics: [
+        Avg,
+        Cardinality,
+        ExtendedStats,
+        Max,
+        MedianAbsoluteDeviation,
+        Min,
+        PercentileRanks,
+        Percentiles,
+        Stats,
+        Sum,
+        TopHits,
+        ValueCount,
+        WeightedAvg
+    ],
+    pipelines: [
+        AvgBucket,
+        BucketScript,
+        BucketSelector,
+        BucketSort,
+        CumulativeSum,
+        Derivative,
+        MaxBucket,
+        MinBucket,
+        MovingFn,
+        SerialDiff,
+        StatsBucket,
+        SumBucket
+    ],
+    buckets: [
+        Children,
+        Composite,
+        DateHistogram,
+        DateRange,
+        Filter,
+        Filters,
+        Global,
+        Histogram,
+        Missing,
+        MultiTerms,
+        Nested,
+        Parent,
+        Range,
+        ReverseNested,
+        SignificantTerms,
+        Terms
+    ]
+);
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    field::{Field, FieldEntry},
+    query::QueryValue,
+    sort::Order,
+};
+
+use super::Aggregations;
+
+/// One bucket per unique combination of values of several fields
+///
+/// Reference: [Multi terms aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-multi-terms-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::aggregation::{
+///     multi_terms::{MultiTerms, MultiTermsSource},
+///     Aggregation,
+/// };
+///
+/// let agg = Aggregation::MultiTerms(
+///     MultiTerms::new()
+///         .term(MultiTermsSource::new("genre"))
+///         .term(MultiTermsSource::new("product").missing("unknown"))
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MultiTerms {
+    terms: Vec<MultiTermsSource>,
+    size: Option<u32>,
+    shard_size: Option<u32>,
+    min_doc_count: Option<u64>,
+    #[serde(default, deserialize_with = "crate::de::one_or_many")]
+    order: Option<Vec<FieldEntry<Order>>>,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl MultiTerms {
+    pub fn new() -> Self {
+        Self {
+            terms: vec![],
+            size: None,
+            shard_size: None,
+            min_doc_count: None,
+            order: None,
+            aggs: None,
+            meta: None,
+        }
+    }
+
+    pub fn term(mut self, v: MultiTermsSource) -> Self {
+        self.terms.push(v);
+
+        self
+    }
+
+    pub fn size(mut self, v: u32) -> Self {
+        self.size = Some(v);
+
+        self
+    }
+
+    pub fn shard_size(mut self, v: u32) -> Self {
+        self.shard_size = Some(v);
+
+        self
+    }
+
+    pub fn min_doc_count(mut self, v: u64) -> Self {
+        self.min_doc_count = Some(v);
+
+        self
+    }
+
+    /// Orders the buckets by `_count`, `_key` or the path of a sub-aggregation,
+    /// the following calls break the ties of the previous ones
+    pub fn order(mut self, key: impl Into<Field>, order: Order) -> Self {
+        self.order.get_or_insert_with(Vec::new).push(FieldEntry::new(key, order));
+
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MultiTermsSource {
+    field: Field,
+    missing: Option<QueryValue>,
+}
+
+impl MultiTermsSource {
+    pub fn new(field: impl Into<Field>) -> Self {
+        Self {
+            field: field.into(),
+            missing: None,
+        }
+    }
+
+    pub fn missing(mut self, v: impl Into<QueryValue>) -> Self {
+        self.missing = Some(v.into());
+
+        self
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::field::Field;
+
+use super::Aggregations;
+
+/// A single bucket of the nested objects at `path`, their sub-aggregations
+/// working on the fields of the nested objects
+///
+/// Reference: [Nested aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-nested-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::aggregation::{
+///     metric::Min,
+///     nested::{Nested, ReverseNested},
+///     terms::Terms,
+///     Aggregation,
+/// };
+///
+/// let agg = Aggregation::Nested(
+///     Nested::new("resellers")
+///         .agg("min_price", Aggregation::Min(Min::new().field("resellers.price")))
+///         .agg(
+///             "resellers",
+///             Aggregation::Terms(
+///                 Terms::new()
+///                     .field("resellers.reseller")
+///                     .agg(
+///                         "top_products",
+///                         Aggregation::ReverseNested(
+///                             ReverseNested::new().agg("names", Aggregation::Terms(Terms::new().field("name")))
+///                         )
+///                     )
+///             )
+///         )
+/// );
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Nested {
+    path: Field,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Nested {
+    pub fn new(path: impl Into<Field>) -> Self {
+        Self {
+            path: path.into(),
+            aggs: None,
+            meta: None,
+        }
+    }
+}
+
+/// Joins back from nested objects to their parent documents, or to the
+/// nested objects at `path`, only valid inside a nested aggregation
+///
+/// Reference: [Reverse nested aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-reverse-nested-aggregation.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReverseNested {
+    path: Option<Field>,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl ReverseNested {
+    /// Joins back to the root documents
+    pub fn new() -> Self {
+        Self {
+            path: None,
+            aggs: None,
+            meta: None,
+        }
+    }
+
+    /// Joins back to the nested objects at `path` instead of the root documents
+    pub fn path(mut self, v: impl Into<Field>) -> Self {
+        self.path = Some(v.into());
+
+        self
+    }
+}
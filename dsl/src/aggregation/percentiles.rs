@@ -0,0 +1,213 @@
+use std::fmt;
+use serde::{
+    de::{self, MapAccess, Visitor},
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use serde_with::skip_serializing_none;
+
+use super::values_source::{impl_values_source, ValuesSource};
+
+/// Reference: [Percentiles aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-metrics-percentile-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::aggregation::{
+///     percentiles::{Percentiles, PercentilesMethod},
+///     Aggregation,
+/// };
+///
+/// let agg = Aggregation::Percentiles(
+///     Percentiles::new()
+///         .field("load_time")
+///         .percents([95.0, 99.0, 99.9])
+///         .method(PercentilesMethod::Hdr { number_of_significant_value_digits: 3 })
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Percentiles {
+    #[serde(flatten)]
+    source: ValuesSource,
+    percents: Option<Vec<f64>>,
+    keyed: Option<bool>,
+    #[serde(flatten)]
+    method: Method,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Percentiles {
+    pub fn new() -> Self {
+        Self {
+            source: ValuesSource::new(),
+            percents: None,
+            keyed: None,
+            method: Method(None),
+            meta: None,
+        }
+    }
+
+    /// Defaults to `[1, 5, 25, 50, 75, 95, 99]`
+    pub fn percents(mut self, v: impl IntoIterator<Item = f64>) -> Self {
+        self.percents = Some(v.into_iter().collect());
+
+        self
+    }
+
+    pub fn keyed(mut self, v: bool) -> Self {
+        self.keyed = Some(v);
+
+        self
+    }
+
+    pub fn method(mut self, v: PercentilesMethod) -> Self {
+        self.method = Method(Some(v));
+
+        self
+    }
+}
+
+impl_values_source!(Percentiles);
+
+/// Reference: [Percentile ranks aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-metrics-percentile-rank-aggregation.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PercentileRanks {
+    #[serde(flatten)]
+    source: ValuesSource,
+    values: Vec<f64>,
+    keyed: Option<bool>,
+    #[serde(flatten)]
+    method: Method,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl PercentileRanks {
+    pub fn new(values: impl IntoIterator<Item = f64>) -> Self {
+        Self {
+            source: ValuesSource::new(),
+            values: values.into_iter().collect(),
+            keyed: None,
+            method: Method(None),
+            meta: None,
+        }
+    }
+
+    pub fn keyed(mut self, v: bool) -> Self {
+        self.keyed = Some(v);
+
+        self
+    }
+
+    pub fn method(mut self, v: PercentilesMethod) -> Self {
+        self.method = Method(Some(v));
+
+        self
+    }
+}
+
+impl_values_source!(PercentileRanks);
+
+/// The algorithm approximating the percentiles, defaults to TDigest
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PercentilesMethod {
+    TDigest {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        compression: Option<f64>,
+    },
+    /// HDR Histogram, faster than TDigest at the cost of a larger memory footprint
+    Hdr {
+        number_of_significant_value_digits: u8,
+    },
+}
+
+/// The optional `tdigest` or `hdr` key, read explicitly so that a malformed
+/// method is reported instead of being left out
+#[derive(Clone, Debug)]
+struct Method(Option<PercentilesMethod>);
+
+impl Serialize for Method {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Method {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "lowercase")]
+        enum MethodKey {
+            TDigest,
+            Hdr,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct TDigestOptions {
+            compression: Option<f64>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct HdrOptions {
+            number_of_significant_value_digits: u8,
+        }
+
+        struct MethodVisitor;
+
+        impl<'de> Visitor<'de> for MethodVisitor {
+            type Value = Method;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an optional `tdigest` or `hdr` method")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut method = None;
+
+                while let Some(key) = map.next_key::<MethodKey>()? {
+                    if method.is_some() {
+                        return Err(de::Error::custom("expected a single percentiles method, found more"));
+                    }
+
+                    method = Some(match key {
+                        MethodKey::TDigest => {
+                            let opts = map.next_value::<TDigestOptions>()?;
+
+                            PercentilesMethod::TDigest {
+                                compression: opts.compression,
+                            }
+                        },
+                        MethodKey::Hdr => {
+                            let opts = map.next_value::<HdrOptions>()?;
+
+                            PercentilesMethod::Hdr {
+                                number_of_significant_value_digits: opts.number_of_significant_value_digits,
+                            }
+                        },
+                    });
+                }
+
+                Ok(Method(method))
+            }
+        }
+
+        deserializer.deserialize_struct("Method", &["tdigest", "hdr"], MethodVisitor)
+    }
+}
@@ -0,0 +1,474 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use macros::{DisplayCase, FromStrCase};
+use serde::{Deserialize, Serialize};
+use serde_with::{skip_serializing_none, DeserializeFromStr, SerializeDisplay};
+use crate::{
+    script::Script,
+    sort::Sort,
+};
+
+use super::buckets_path::BucketsPath;
+
+macro_rules! declare_sibling_pipeline {
+    ($($(#[$meta:meta])* $agg:ident,)*) => {
+        $(
+            $(#[$meta])*
+            #[skip_serializing_none]
+            #[derive(Clone, Debug, Serialize, Deserialize)]
+            #[serde(deny_unknown_fields)]
+            pub struct $agg {
+                buckets_path: BucketsPath,
+                gap_policy: Option<GapPolicy>,
+                format: Option<Cow<'static, str>>,
+                #[serde(skip)]
+                pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+            }
+
+            impl $agg {
+                pub fn new(buckets_path: BucketsPath) -> Self {
+                    Self {
+                        buckets_path,
+                        gap_policy: None,
+                        format: None,
+                        meta: None,
+                    }
+                }
+
+                pub fn gap_policy(mut self, v: GapPolicy) -> Self {
+                    self.gap_policy = Some(v);
+
+                    self
+                }
+
+                pub fn format(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+                    self.format = Some(v.into());
+
+                    self
+                }
+
+                pub(super) fn buckets_paths(&self) -> Vec<&BucketsPath> {
+                    vec![&self.buckets_path]
+                }
+            }
+        )*
+    };
+}
+
+declare_sibling_pipeline! {
+    /// Reference: [Avg bucket aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline-avg-bucket-aggregation.html)
+    ///
+    /// # Example
+    /// ```
+    /// use dsl::{
+    ///     aggregation::{
+    ///         buckets_path::BucketsPath,
+    ///         histogram::{CalendarInterval, DateHistogram},
+    ///         metric::Sum,
+    ///         pipeline::AvgBucket,
+    ///         Aggregation,
+    ///     },
+    ///     search::Search,
+    /// };
+    ///
+    /// let search = Search::new()
+    ///     .agg(
+    ///         "sales_per_month",
+    ///         Aggregation::DateHistogram(
+    ///             DateHistogram::calendar(CalendarInterval::Month)
+    ///                 .field("date")
+    ///                 .agg("sales", Aggregation::Sum(Sum::new().field("price")))
+    ///         )
+    ///     )
+    ///     .agg(
+    ///         "avg_monthly_sales",
+    ///         Aggregation::AvgBucket(AvgBucket::new(BucketsPath::new("sales_per_month").agg("sales")))
+    ///     );
+    ///
+    /// assert!(search.validate().is_ok());
+    /// ```
+    AvgBucket,
+    /// Reference: [Max bucket aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline-max-bucket-aggregation.html)
+    MaxBucket,
+    /// Reference: [Min bucket aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline-min-bucket-aggregation.html)
+    MinBucket,
+    /// Reference: [Sum bucket aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline-sum-bucket-aggregation.html)
+    SumBucket,
+    /// Reference: [Stats bucket aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline-stats-bucket-aggregation.html)
+    StatsBucket,
+}
+
+/// Reference: [Derivative aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline-derivative-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     aggregation::{
+///         buckets_path::BucketsPath,
+///         histogram::{CalendarInterval, DateHistogram},
+///         metric::Sum,
+///         pipeline::Derivative,
+///         Aggregation,
+///     },
+///     search::Search,
+/// };
+///
+/// let search = Search::new().agg(
+///     "sales_per_month",
+///     Aggregation::DateHistogram(
+///         DateHistogram::calendar(CalendarInterval::Month)
+///             .field("date")
+///             .agg("sales", Aggregation::Sum(Sum::new().field("price")))
+///             .agg("sales_deriv", Aggregation::Derivative(Derivative::new(BucketsPath::new("sales")).unit("day")))
+///     )
+/// );
+///
+/// assert!(search.validate().is_ok());
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Derivative {
+    buckets_path: BucketsPath,
+    gap_policy: Option<GapPolicy>,
+    format: Option<Cow<'static, str>>,
+    unit: Option<Cow<'static, str>>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Derivative {
+    pub fn new(buckets_path: BucketsPath) -> Self {
+        Self {
+            buckets_path,
+            gap_policy: None,
+            format: None,
+            unit: None,
+            meta: None,
+        }
+    }
+
+    pub fn gap_policy(mut self, v: GapPolicy) -> Self {
+        self.gap_policy = Some(v);
+
+        self
+    }
+
+    pub fn format(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.format = Some(v.into());
+
+        self
+    }
+
+    /// Also returns the derivative per unit of time, e.g. `day`, in `normalized_value`
+    pub fn unit(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.unit = Some(v.into());
+
+        self
+    }
+
+    pub(super) fn buckets_paths(&self) -> Vec<&BucketsPath> {
+        vec![&self.buckets_path]
+    }
+}
+
+/// Reference: [Cumulative sum aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline-cumulative-sum-aggregation.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CumulativeSum {
+    buckets_path: BucketsPath,
+    format: Option<Cow<'static, str>>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl CumulativeSum {
+    pub fn new(buckets_path: BucketsPath) -> Self {
+        Self {
+            buckets_path,
+            format: None,
+            meta: None,
+        }
+    }
+
+    pub fn format(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.format = Some(v.into());
+
+        self
+    }
+
+    pub(super) fn buckets_paths(&self) -> Vec<&BucketsPath> {
+        vec![&self.buckets_path]
+    }
+}
+
+/// Runs a script over a sliding window of the values, e.g. `MovingFunctions.unweightedAvg(values)`
+///
+/// Reference: [Moving function aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline-movfn-aggregation.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MovingFn {
+    buckets_path: BucketsPath,
+    window: u32,
+    script: Script,
+    shift: Option<i32>,
+    gap_policy: Option<GapPolicy>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl MovingFn {
+    pub fn new(buckets_path: BucketsPath, window: u32, script: Script) -> Self {
+        Self {
+            buckets_path,
+            window,
+            script,
+            shift: None,
+            gap_policy: None,
+            meta: None,
+        }
+    }
+
+    /// Shifts the window, which excludes the current bucket by default, 1 includes it
+    pub fn shift(mut self, v: i32) -> Self {
+        self.shift = Some(v);
+
+        self
+    }
+
+    pub fn gap_policy(mut self, v: GapPolicy) -> Self {
+        self.gap_policy = Some(v);
+
+        self
+    }
+
+    pub(super) fn buckets_paths(&self) -> Vec<&BucketsPath> {
+        vec![&self.buckets_path]
+    }
+}
+
+/// Computes a value per bucket with a script, the paths being available as `params.<name>`
+///
+/// Reference: [Bucket script aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline-bucket-script-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     aggregation::{buckets_path::BucketsPath, pipeline::BucketScript, Aggregation},
+///     script::Script,
+/// };
+///
+/// let agg = Aggregation::BucketScript(
+///     BucketScript::new(Script::new("params.tShirtSales / params.totalSales * 100"))
+///         .path("tShirtSales", BucketsPath::new("t-shirts").agg("sales"))
+///         .path("totalSales", BucketsPath::new("total_sales"))
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BucketScript {
+    buckets_path: BTreeMap<Cow<'static, str>, BucketsPath>,
+    script: Script,
+    gap_policy: Option<GapPolicy>,
+    format: Option<Cow<'static, str>>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl BucketScript {
+    pub fn new(script: Script) -> Self {
+        Self {
+            buckets_path: BTreeMap::new(),
+            script,
+            gap_policy: None,
+            format: None,
+            meta: None,
+        }
+    }
+
+    /// Binds the values of a path to the script variable `name`
+    pub fn path(mut self, name: impl Into<Cow<'static, str>>, path: BucketsPath) -> Self {
+        self.buckets_path.insert(name.into(), path);
+
+        self
+    }
+
+    pub fn gap_policy(mut self, v: GapPolicy) -> Self {
+        self.gap_policy = Some(v);
+
+        self
+    }
+
+    pub fn format(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.format = Some(v.into());
+
+        self
+    }
+
+    pub(super) fn buckets_paths(&self) -> Vec<&BucketsPath> {
+        self.buckets_path.values().collect()
+    }
+}
+
+/// Keeps the buckets for which a script returns `true`
+///
+/// Reference: [Bucket selector aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline-bucket-selector-aggregation.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BucketSelector {
+    buckets_path: BTreeMap<Cow<'static, str>, BucketsPath>,
+    script: Script,
+    gap_policy: Option<GapPolicy>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl BucketSelector {
+    pub fn new(script: Script) -> Self {
+        Self {
+            buckets_path: BTreeMap::new(),
+            script,
+            gap_policy: None,
+            meta: None,
+        }
+    }
+
+    /// Binds the values of a path to the script variable `name`
+    pub fn path(mut self, name: impl Into<Cow<'static, str>>, path: BucketsPath) -> Self {
+        self.buckets_path.insert(name.into(), path);
+
+        self
+    }
+
+    pub fn gap_policy(mut self, v: GapPolicy) -> Self {
+        self.gap_policy = Some(v);
+
+        self
+    }
+
+    pub(super) fn buckets_paths(&self) -> Vec<&BucketsPath> {
+        self.buckets_path.values().collect()
+    }
+}
+
+/// Sorts and truncates the buckets of its parent, sorting on `_key`, `_count`
+/// or sub-aggregations
+///
+/// Reference: [Bucket sort aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline-bucket-sort-aggregation.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BucketSort {
+    sort: Option<Sort>,
+    from: Option<u32>,
+    size: Option<u32>,
+    gap_policy: Option<GapPolicy>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl BucketSort {
+    pub fn new() -> Self {
+        Self {
+            sort: None,
+            from: None,
+            size: None,
+            gap_policy: None,
+            meta: None,
+        }
+    }
+
+    pub fn sort(mut self, v: Sort) -> Self {
+        self.sort = Some(v);
+
+        self
+    }
+
+    pub fn from(mut self, v: u32) -> Self {
+        self.from = Some(v);
+
+        self
+    }
+
+    pub fn size(mut self, v: u32) -> Self {
+        self.size = Some(v);
+
+        self
+    }
+
+    pub fn gap_policy(mut self, v: GapPolicy) -> Self {
+        self.gap_policy = Some(v);
+
+        self
+    }
+
+    pub(super) fn buckets_paths(&self) -> Vec<&BucketsPath> {
+        vec![]
+    }
+}
+
+/// Subtracts from each value the value `lag` buckets before
+///
+/// Reference: [Serial differencing aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline-serialdiff-aggregation.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SerialDiff {
+    buckets_path: BucketsPath,
+    lag: Option<u32>,
+    gap_policy: Option<GapPolicy>,
+    format: Option<Cow<'static, str>>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl SerialDiff {
+    pub fn new(buckets_path: BucketsPath) -> Self {
+        Self {
+            buckets_path,
+            lag: None,
+            gap_policy: None,
+            format: None,
+            meta: None,
+        }
+    }
+
+    /// Defaults to 1
+    pub fn lag(mut self, v: u32) -> Self {
+        self.lag = Some(v);
+
+        self
+    }
+
+    pub fn gap_policy(mut self, v: GapPolicy) -> Self {
+        self.gap_policy = Some(v);
+
+        self
+    }
+
+    pub fn format(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.format = Some(v.into());
+
+        self
+    }
+
+    pub(super) fn buckets_paths(&self) -> Vec<&BucketsPath> {
+        vec![&self.buckets_path]
+    }
+}
+
+/// How to handle the buckets without a value, defaults to `skip`
+///
+/// Reference: [Dealing with gaps in the data](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-pipeline.html#gap-policy)
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
+#[display_case(case = "snakecase")]
+pub enum GapPolicy {
+    Skip,
+    InsertZeros,
+    KeepValues,
+}
@@ -0,0 +1,182 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    query::QueryValue,
+    types::number::Number,
+};
+
+use super::{
+    values_source::{impl_values_source, ValuesSource},
+    Aggregations,
+};
+
+/// One bucket per range of values, `from` being included and `to` excluded
+///
+/// Reference: [Range aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-range-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::aggregation::{
+///     range::{Range, RangeBucket},
+///     Aggregation,
+/// };
+///
+/// let agg = Aggregation::Range(
+///     Range::new()
+///         .field("price")
+///         .range(RangeBucket::new().to(100.0))
+///         .range(RangeBucket::new().from(100.0).to(200.0))
+///         .range(RangeBucket::new().from(200.0).key("expensive"))
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Range {
+    #[serde(flatten)]
+    source: ValuesSource,
+    ranges: Vec<RangeBucket<Number>>,
+    keyed: Option<bool>,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Range {
+    pub fn new() -> Self {
+        Self {
+            source: ValuesSource::new(),
+            ranges: vec![],
+            keyed: None,
+            aggs: None,
+            meta: None,
+        }
+    }
+
+    pub fn range(mut self, v: RangeBucket<Number>) -> Self {
+        self.ranges.push(v);
+
+        self
+    }
+
+    pub fn keyed(mut self, v: bool) -> Self {
+        self.keyed = Some(v);
+
+        self
+    }
+}
+
+/// Reference: [Date range aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-daterange-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::aggregation::{
+///     range::{DateRange, RangeBucket},
+///     Aggregation,
+/// };
+///
+/// let agg = Aggregation::DateRange(
+///     DateRange::new()
+///         .field("date")
+///         .format("MM-yyyy")
+///         .range(RangeBucket::new().to("now-10M/M"))
+///         .range(RangeBucket::new().from("now-10M/M"))
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DateRange {
+    #[serde(flatten)]
+    source: ValuesSource,
+    format: Option<Cow<'static, str>>,
+    time_zone: Option<Cow<'static, str>>,
+    ranges: Vec<RangeBucket<QueryValue>>,
+    keyed: Option<bool>,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl DateRange {
+    pub fn new() -> Self {
+        Self {
+            source: ValuesSource::new(),
+            format: None,
+            time_zone: None,
+            ranges: vec![],
+            keyed: None,
+            aggs: None,
+            meta: None,
+        }
+    }
+
+    /// Dates, date math expressions or milliseconds since the epoch
+    pub fn range(mut self, v: RangeBucket<QueryValue>) -> Self {
+        self.ranges.push(v);
+
+        self
+    }
+
+    /// The format of the dates of the ranges and of the keys of the response
+    pub fn format(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.format = Some(v.into());
+
+        self
+    }
+
+    pub fn time_zone(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.time_zone = Some(v.into());
+
+        self
+    }
+
+    pub fn keyed(mut self, v: bool) -> Self {
+        self.keyed = Some(v);
+
+        self
+    }
+}
+
+impl_values_source!(Range, DateRange);
+
+/// A range of a range aggregation, open ended when missing `from` or `to`
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RangeBucket<T> {
+    key: Option<Cow<'static, str>>,
+    from: Option<T>,
+    to: Option<T>,
+}
+
+impl<T> RangeBucket<T> {
+    pub fn new() -> Self {
+        Self {
+            key: None,
+            from: None,
+            to: None,
+        }
+    }
+
+    pub fn key(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.key = Some(v.into());
+
+        self
+    }
+
+    pub fn from(mut self, v: impl Into<T>) -> Self {
+        self.from = Some(v.into());
+
+        self
+    }
+
+    pub fn to(mut self, v: impl Into<T>) -> Self {
+        self.to = Some(v.into());
+
+        self
+    }
+}
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    field::Field,
+    query::Query,
+};
+
+use super::{
+    terms::{TermsExclude, TermsInclude},
+    Aggregations,
+};
+
+/// The terms which are unusually frequent in the matching documents compared
+/// to a background set, the whole index by default
+///
+/// Reference: [Significant terms aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-significantterms-aggregation.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SignificantTerms {
+    field: Field,
+    size: Option<u32>,
+    shard_size: Option<u32>,
+    min_doc_count: Option<u64>,
+    shard_min_doc_count: Option<u64>,
+    include: Option<TermsInclude>,
+    exclude: Option<TermsExclude>,
+    background_filter: Option<Query>,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl SignificantTerms {
+    pub fn new(field: impl Into<Field>) -> Self {
+        Self {
+            field: field.into(),
+            size: None,
+            shard_size: None,
+            min_doc_count: None,
+            shard_min_doc_count: None,
+            include: None,
+            exclude: None,
+            background_filter: None,
+            aggs: None,
+            meta: None,
+        }
+    }
+
+    pub fn size(mut self, v: u32) -> Self {
+        self.size = Some(v);
+
+        self
+    }
+
+    pub fn shard_size(mut self, v: u32) -> Self {
+        self.shard_size = Some(v);
+
+        self
+    }
+
+    /// Defaults to 3
+    pub fn min_doc_count(mut self, v: u64) -> Self {
+        self.min_doc_count = Some(v);
+
+        self
+    }
+
+    pub fn shard_min_doc_count(mut self, v: u64) -> Self {
+        self.shard_min_doc_count = Some(v);
+
+        self
+    }
+
+    pub fn include(mut self, v: TermsInclude) -> Self {
+        self.include = Some(v);
+
+        self
+    }
+
+    pub fn exclude(mut self, v: TermsExclude) -> Self {
+        self.exclude = Some(v);
+
+        self
+    }
+
+    /// Narrows the background set the frequencies are compared to
+    pub fn background_filter(mut self, v: Query) -> Self {
+        self.background_filter = Some(v);
+
+        self
+    }
+}
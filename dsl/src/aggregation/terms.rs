@@ -0,0 +1,173 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    field::{Field, FieldEntry},
+    sort::Order,
+};
+
+use super::{
+    values_source::{impl_values_source, ValuesSource},
+    Aggregations,
+};
+
+/// One bucket per unique value
+///
+/// Reference: [Terms aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-bucket-terms-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     aggregation::{
+///         metric::Avg,
+///         terms::{Terms, TermsExclude},
+///         Aggregation,
+///     },
+///     search::Search,
+///     sort::Order,
+/// };
+///
+/// let search = Search::new().agg(
+///     "genres",
+///     Aggregation::Terms(
+///         Terms::new()
+///             .field("genre")
+///             .size(5)
+///             .order("avg_play_count", Order::Desc)
+///             .exclude(TermsExclude::values(["jazz"]))
+///             .agg("avg_play_count", Aggregation::Avg(Avg::new().field("play_count")))
+///     )
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Terms {
+    #[serde(flatten)]
+    source: ValuesSource,
+    size: Option<u32>,
+    shard_size: Option<u32>,
+    min_doc_count: Option<u64>,
+    #[serde(default, deserialize_with = "crate::de::one_or_many")]
+    order: Option<Vec<FieldEntry<Order>>>,
+    include: Option<TermsInclude>,
+    exclude: Option<TermsExclude>,
+    #[serde(skip)]
+    pub(super) aggs: Option<Aggregations>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Terms {
+    pub fn new() -> Self {
+        Self {
+            source: ValuesSource::new(),
+            size: None,
+            shard_size: None,
+            min_doc_count: None,
+            order: None,
+            include: None,
+            exclude: None,
+            aggs: None,
+            meta: None,
+        }
+    }
+
+    /// Number of buckets to return, defaults to 10
+    pub fn size(mut self, v: u32) -> Self {
+        self.size = Some(v);
+
+        self
+    }
+
+    pub fn shard_size(mut self, v: u32) -> Self {
+        self.shard_size = Some(v);
+
+        self
+    }
+
+    pub fn min_doc_count(mut self, v: u64) -> Self {
+        self.min_doc_count = Some(v);
+
+        self
+    }
+
+    /// Orders the buckets by `_count`, `_key` or the path of a sub-aggregation,
+    /// the following calls break the ties of the previous ones
+    pub fn order(mut self, key: impl Into<Field>, order: Order) -> Self {
+        self.order.get_or_insert_with(Vec::new).push(FieldEntry::new(key, order));
+
+        self
+    }
+
+    pub fn include(mut self, v: TermsInclude) -> Self {
+        self.include = Some(v);
+
+        self
+    }
+
+    pub fn exclude(mut self, v: TermsExclude) -> Self {
+        self.exclude = Some(v);
+
+        self
+    }
+}
+
+impl_values_source!(Terms);
+
+/// The terms to aggregate on, the others being left out
+#[allow(dead_code)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TermsInclude {
+    Pattern(Cow<'static, str>),
+    Values(Vec<Cow<'static, str>>),
+    /// Only the terms of one of `num_partitions` groups, to aggregate a large
+    /// number of terms over several requests
+    Partition {
+        partition: u32,
+        num_partitions: u32,
+    },
+}
+
+impl TermsInclude {
+    pub fn pattern(v: impl Into<Cow<'static, str>>) -> Self {
+        Self::Pattern(v.into())
+    }
+
+    pub fn values<T>(v: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        Self::Values(v.into_iter().map(Into::into).collect())
+    }
+
+    pub fn partition(partition: u32, num_partitions: u32) -> Self {
+        Self::Partition {
+            partition,
+            num_partitions,
+        }
+    }
+}
+
+/// The terms left out of the aggregation
+#[allow(dead_code)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TermsExclude {
+    Pattern(Cow<'static, str>),
+    Values(Vec<Cow<'static, str>>),
+}
+
+impl TermsExclude {
+    pub fn pattern(v: impl Into<Cow<'static, str>>) -> Self {
+        Self::Pattern(v.into())
+    }
+
+    pub fn values<T>(v: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        Self::Values(v.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    field::Field,
+    sort::Sort,
+    source::SourceFilter,
+};
+
+/// The most relevant documents of each bucket
+///
+/// Reference: [Top hits aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-metrics-top-hits-aggregation.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TopHits {
+    from: Option<u32>,
+    size: Option<u32>,
+    sort: Option<Sort>,
+    _source: Option<SourceFilter>,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl TopHits {
+    pub fn new() -> Self {
+        Self {
+            from: None,
+            size: None,
+            sort: None,
+            _source: None,
+            meta: None,
+        }
+    }
+
+    pub fn from(mut self, v: u32) -> Self {
+        self.from = Some(v);
+
+        self
+    }
+
+    /// Defaults to 3
+    pub fn size(mut self, v: u32) -> Self {
+        self.size = Some(v);
+
+        self
+    }
+
+    pub fn sort(mut self, v: Sort) -> Self {
+        self.sort = Some(v);
+
+        self
+    }
+
+    /// Whether to return the `_source` of the hits
+    pub fn source(mut self, v: bool) -> Self {
+        self._source = Some(SourceFilter::Enabled(v));
+
+        self
+    }
+
+    /// Only return these fields of the `_source` of the hits
+    pub fn source_includes<T>(mut self, v: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<Field>,
+    {
+        self._source = Some(SourceFilter::Includes(v.into_iter().map(Into::into).collect()));
+
+        self
+    }
+}
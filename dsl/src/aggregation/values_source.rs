@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    field::Field,
+    query::QueryValue,
+    script::Script,
+};
+
+/// The values an aggregation works on, taken from a `field` or computed by a
+/// `script`, with the value to use for the documents `missing` one
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ValuesSource {
+    field: Option<Field>,
+    missing: Option<QueryValue>,
+    script: Option<Script>,
+}
+
+impl ValuesSource {
+    pub fn new() -> Self {
+        Self {
+            field: None,
+            missing: None,
+            script: None,
+        }
+    }
+
+    pub fn field(mut self, v: impl Into<Field>) -> Self {
+        self.field = Some(v.into());
+
+        self
+    }
+
+    pub fn missing(mut self, v: impl Into<QueryValue>) -> Self {
+        self.missing = Some(v.into());
+
+        self
+    }
+
+    pub fn script(mut self, v: Script) -> Self {
+        self.script = Some(v);
+
+        self
+    }
+}
+
+/// Implement the `field`, `missing` and `script` setters of aggregations
+/// holding a `#[serde(flatten)] source: ValuesSource`
+macro_rules! impl_values_source {
+    ($($agg:ident),*) => {
+        $(
+            impl $agg {
+                pub fn field(mut self, v: impl Into<$crate::field::Field>) -> Self {
+                    self.source = self.source.field(v);
+
+                    self
+                }
+
+                pub fn missing(mut self, v: impl Into<$crate::query::QueryValue>) -> Self {
+                    self.source = self.source.missing(v);
+
+                    self
+                }
+
+                pub fn script(mut self, v: $crate::script::Script) -> Self {
+                    self.source = self.source.script(v);
+
+                    self
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_values_source;
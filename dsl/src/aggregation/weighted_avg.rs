@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use super::values_source::ValuesSource;
+
+/// Reference: [Weighted avg aggregation](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/search-aggregations-metrics-weight-avg-aggregation.html)
+///
+/// # Example
+/// ```
+/// use dsl::aggregation::{
+///     values_source::ValuesSource,
+///     weighted_avg::WeightedAvg,
+///     Aggregation,
+/// };
+///
+/// let agg = Aggregation::WeightedAvg(
+///     WeightedAvg::new(ValuesSource::new().field("grade"), ValuesSource::new().field("weight").missing(3))
+/// );
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WeightedAvg {
+    value: ValuesSource,
+    weight: ValuesSource,
+    #[serde(skip)]
+    pub(super) meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl WeightedAvg {
+    pub fn new(value: ValuesSource, weight: ValuesSource) -> Self {
+        Self {
+            value,
+            weight,
+            meta: None,
+        }
+    }
+}
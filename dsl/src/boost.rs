@@ -1,9 +1,9 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use crate::types::EqualsToDefault;
 
 /// Floating point number used to decrease or increase the relevance
 /// scores of a query. Defaults to 1.0.
-#[derive(Clone, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Boost(pub f32);
 
 impl Default for Boost {
@@ -1,8 +1,8 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use crate::types::EqualsToDefault;
 
 /// Case insensitive, defaults to false
-#[derive(Clone, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CaseInsensitive(pub bool);
 
 impl Default for CaseInsensitive {
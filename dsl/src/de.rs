@@ -0,0 +1,88 @@
+use std::fmt;
+use std::marker::PhantomData;
+use serde::{
+    de::{
+        self,
+        MapAccess,
+        Visitor,
+    },
+    Deserialize,
+    Deserializer,
+};
+use crate::field::Field;
+
+/// Deserialize the single entry map `{"<field>": <value>}` used by the
+/// field-keyed clauses, e.g. `match`, `term`, `range` and sort clauses.
+pub(crate) fn deserialize_field_entry<'de, D, T>(deserializer: D) -> Result<(Field, T), D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    struct FieldEntryVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for FieldEntryVisitor<T>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = (Field, T);
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a map with a single field entry")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let entry = map.next_entry::<Field, T>()?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+
+            if map.next_key::<de::IgnoredAny>()?.is_some() {
+                return Err(de::Error::custom("expected a single field entry, found more"));
+            }
+
+            Ok(entry)
+        }
+    }
+
+    deserializer.deserialize_map(FieldEntryVisitor(PhantomData))
+}
+
+/// Deserialize a value which Elasticsearch accepts either as a single item or
+/// as an array of items, e.g. the occurrences of a `bool` query.
+pub(crate) fn one_or_many<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    one_or_many_required(deserializer).map(Some)
+}
+
+/// Same as [`one_or_many`], for a required value, e.g. the `like` of a
+/// `more_like_this` query.
+pub(crate) fn one_or_many_required<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(v) => Ok(vec![v]),
+        OneOrMany::Many(v) => Ok(v),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+/// A value written either in its short form, e.g. `{"term": {"user.id": "kimchy"}}`,
+/// or in its full form, e.g. `{"term": {"user.id": {"value": "kimchy"}}}`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum ShortOrFull<S, F> {
+    Short(S),
+    Full(F),
+}
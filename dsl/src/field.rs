@@ -1,11 +1,123 @@
 use std::borrow::Cow;
-use serde::Serialize;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use crate::de::deserialize_field_entry;
 
-#[derive(Clone, PartialEq ,Eq, Hash, Serialize)]
-pub struct Field<'a>(Cow<'a, str>);
+#[derive(Clone, Debug, PartialEq ,Eq, Hash, Serialize, Deserialize)]
+pub struct Field(Cow<'static, str>);
 
-impl<'a> From<&'a str> for Field<'a> {
-    fn from(s: &'a str) -> Self {
+impl From<&'static str> for Field {
+    fn from(s: &'static str) -> Self {
         Self(s.into())
     }
 }
+
+impl From<String> for Field {
+    fn from(s: String) -> Self {
+        Self(s.into())
+    }
+}
+
+impl AsRef<str> for Field {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A field name with an optional per-field boost, written as `title^3`
+#[derive(Clone, Debug, PartialEq, SerializeDisplay, DeserializeFromStr)]
+pub struct BoostedField {
+    field: Field,
+    boost: Option<f32>,
+}
+
+impl BoostedField {
+    pub fn new(field: impl Into<Field>, boost: Option<f32>) -> Self {
+        Self {
+            field: field.into(),
+            boost,
+        }
+    }
+}
+
+impl Display for BoostedField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.boost {
+            Some(boost) => write!(f, "{}^{}", self.field.0, boost),
+            None => write!(f, "{}", self.field.0),
+        }
+    }
+}
+
+impl FromStr for BoostedField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.rsplit_once('^') {
+            Some((field, boost)) => {
+                let boost = boost.parse::<f32>()
+                    .map_err(|_| format!("invalid boost in field `{}`", s))?;
+
+                Ok(Self::new(field.to_owned(), Some(boost)))
+            },
+            None => Ok(Self::new(s.to_owned(), None)),
+        }
+    }
+}
+
+/// The single entry map `{"<field>": <value>}`, e.g. a sort clause, also
+/// flattened into the options struct of the clauses which take options next
+/// to it, e.g. `{"geo_distance": {"pin.location": ..., "distance": "12km"}}`.
+#[derive(Clone, Debug)]
+pub(crate) struct FieldEntry<T> {
+    pub(crate) field: Field,
+    pub(crate) value: T,
+}
+
+impl<T> FieldEntry<T> {
+    pub(crate) fn new(field: impl Into<Field>, value: T) -> Self {
+        Self {
+            field: field.into(),
+            value,
+        }
+    }
+}
+
+impl<T> Serialize for FieldEntry<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(Some(1))?;
+        m.serialize_entry(&self.field, &self.value)?;
+        m.end()
+    }
+}
+
+impl<'de, T> Deserialize<'de> for FieldEntry<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let (field, value) = deserialize_field_entry(deserializer)?;
+
+        Ok(Self {
+            field,
+            value,
+        })
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use crate::types::EqualsToDefault;
+
+/// Ignore an unmapped path or type instead of failing, defaults to false
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IgnoreUnmapped(pub bool);
+
+impl Default for IgnoreUnmapped {
+    fn default() -> Self {
+        Self(false)
+    }
+}
+
+impl EqualsToDefault for IgnoreUnmapped {}
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize, Serializer};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    field::Field,
+    query::Query,
+    types::EqualsToDefault,
+};
+
+/// A k-nearest neighbor search clause over a `dense_vector` field, for the
+/// `knn` section of a search, available since Elasticsearch 8.0
+///
+/// Reference: [k-nearest neighbor (kNN) search](https://www.elastic.co/guide/en/elasticsearch/reference/8.4/knn-search.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     clause,
+///     knn::Knn,
+///     search::Search,
+/// };
+///
+/// let search = Search::new()
+///     .knn(
+///         Knn::new("image-vector", [54.0, 10.0, -2.0], 5, 50)
+///             .filter(clause!(Term, "file-type", "png"))
+///             .similarity(0.8)
+///     );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Knn {
+    field: Field,
+    query_vector: Vec<f32>,
+    k: u32,
+    num_candidates: u32,
+    #[serde(default, deserialize_with = "crate::de::one_or_many")]
+    filter: Option<Vec<Query>>,
+    similarity: Option<f32>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl Knn {
+    pub fn new(
+        field: impl Into<Field>,
+        query_vector: impl IntoIterator<Item = f32>,
+        k: u32,
+        num_candidates: u32,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            query_vector: query_vector.into_iter().collect(),
+            k,
+            num_candidates,
+            filter: None,
+            similarity: None,
+            boost: Default::default(),
+        }
+    }
+
+    /// Only the documents matching all the filters are candidates
+    pub fn filter(mut self, v: Query) -> Self {
+        self.filter.get_or_insert_with(Vec::new).push(v);
+
+        self
+    }
+
+    /// Minimum similarity of a document to be a match
+    pub fn similarity(mut self, v: f32) -> Self {
+        self.similarity = Some(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+/// Writes a single clause as an object, which every 8.x version accepts,
+/// and several clauses as an array, which requires 8.4 or later.
+pub(crate) fn serialize_knn<S>(clauses: &Option<Vec<Knn>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer
+{
+    match clauses.as_deref() {
+        Some([clause]) => clause.serialize(serializer),
+        Some(clauses) => clauses.serialize(serializer),
+        None => serializer.serialize_none(),
+    }
+}
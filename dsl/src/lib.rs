@@ -1,11 +1,19 @@
+#![allow(clippy::derivable_impls, clippy::new_without_default)]
+
+pub mod aggregation;
 pub(crate) mod boost;
 pub(crate) mod case_insensitive;
+pub(crate) mod de;
 pub(crate) mod field;
+pub(crate) mod ignore_unmapped;
+pub mod knn;
 pub mod query;
 pub mod sort;
 pub mod types;
 pub mod search;
+pub mod script;
 pub(crate) mod shortcuts;
+pub(crate) mod source;
 
 mod macros;
 mod tests;
@@ -16,18 +16,17 @@
 /// 
 /// let search = Search::new()
 ///     .query(
-///         Query::new()
-///             .bool(
-///                 Bool::new()
-///                     .must(
-///                         clause!(
-///                             Match,
-///                             "event.action",
-///                             QueryValue::Text("logged-in".to_owned()),
-///                             prefix_length = 3
-///                         )
+///         Query::Bool(
+///             Bool::new()
+///                 .must(
+///                     clause!(
+///                         Match,
+///                         "event.action",
+///                         QueryValue::Text("logged-in".to_owned()),
+///                         prefix_length = 3
 ///                     )
-///             )
+///                 )
+///         )
 ///     )
 ///     .build();
 /// ```
@@ -37,8 +36,8 @@ macro_rules! clause {
         {
             use $crate::query::prelude::*;
 
-            LeafClause::$query(
-                &$query::new()
+            Query::$query(
+                $query::new()
             )
         }
     };
@@ -47,8 +46,8 @@ macro_rules! clause {
         {
             use $crate::query::prelude::*;
 
-            LeafClause::$query(
-                &$query::new($field.into())
+            Query::$query(
+                $query::new($field)
             )
         }
     };
@@ -57,8 +56,8 @@ macro_rules! clause {
         {
             use $crate::query::prelude::*;
 
-            LeafClause::$query(
-                &$query::new($field.into())
+            Query::$query(
+                $query::new($field)
                 $(.$key($value))*
             )
         }
@@ -68,8 +67,8 @@ macro_rules! clause {
         {
             use $crate::query::prelude::*;
 
-            LeafClause::$query(
-                &$query::new($field.into(), $query_value)
+            Query::$query(
+                $query::new($field, $query_value)
             )
         }
     };
@@ -78,8 +77,8 @@ macro_rules! clause {
         {
             use $crate::query::prelude::*;
 
-            LeafClause::$query(
-                &$query::new($field.into(), $query_value)
+            Query::$query(
+                $query::new($field, $query_value)
                 $(.$key($value))*
             )
         }
@@ -91,15 +90,14 @@ macro_rules! clause {
 /// # Example
 /// ```
 /// use dsl::search::Search;
-/// use dsl::sort::{Order, Mode, SortClause};
-/// use dsl::{sort, sort_clause};
+/// use dsl::sort::{Order, Mode, Sort};
+/// use dsl::sort_clause;
 /// 
 /// let search = Search::new()
 ///     .sort(
-///         sort!(
-///             sort_clause!("foo", order = Order::Asc, mode = Mode::Min),
-///             sort_clause!("bar")
-///         )
+///         Sort::new()
+///             .sort(sort_clause!("foo", order = Order::Asc, mode = Mode::Min))
+///             .sort(sort_clause!("bar"))
 ///     );
 /// ```
 #[macro_export]
@@ -1,25 +1,53 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
-use super::LeafClause;
+use crate::{
+    boost::Boost,
+    types::EqualsToDefault,
+};
+use super::{Query, QueryValue};
 
 macro_rules! declare_bool {
     ($($field:ident),*) => {
         #[skip_serializing_none]
-        #[derive(Serialize)]
-        pub struct Bool<'a> {
-            $($field: Option<Vec<LeafClause<'a>>>,)*
+        #[derive(Clone, Debug, Serialize, Deserialize)]
+        #[serde(deny_unknown_fields)]
+        pub struct Bool {
+            $(
+                #[serde(default, deserialize_with = "crate::de::one_or_many")]
+                $field: Option<Vec<Query>>,
+            )*
+            #[serde(default)]
+            minimum_should_match: Option<QueryValue>,
+            #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+            boost: Boost,
         }
 
-        impl<'a> Bool<'a> {
+        impl Bool {
             pub fn new() -> Self {
                 Self {
                     $($field: None,)*
+                    minimum_should_match: None,
+                    boost: Default::default(),
                 }
             }
 
+            /// The number or percentage of `should` clauses the documents
+            /// must match, e.g. `1` or `"75%"`
+            pub fn minimum_should_match(mut self, v: impl Into<QueryValue>) -> Self {
+                self.minimum_should_match = Some(v.into());
+
+                self
+            }
+
+            pub fn boost(mut self, v: f32) -> Self {
+                self.boost = Boost(v);
+
+                self
+            }
+
             $(
                 #[allow(dead_code)]
-                pub fn $field(&mut self, clause: LeafClause<'a>) -> &mut Self {
+                pub fn $field(mut self, clause: Query) -> Self {
                     if self.$field.is_none() {
                         self.$field = Some(vec![clause]);
                     } else {
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use crate::{
+    boost::Boost,
+    types::EqualsToDefault,
+};
+
+use super::Query;
+
+/// Demotes, rather than excludes, the documents matching the `negative` query
+///
+/// Reference: [Boosting query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-boosting-query.html)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Boosting {
+    positive: Box<Query>,
+    negative: Box<Query>,
+    negative_boost: f32,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl Boosting {
+    pub fn new(positive: Query, negative: Query, negative_boost: f32) -> Self {
+        Self {
+            positive: Box::new(positive),
+            negative: Box::new(negative),
+            negative_boost,
+            boost: Default::default(),
+        }
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
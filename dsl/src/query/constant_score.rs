@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use crate::{
+    boost::Boost,
+    types::EqualsToDefault,
+};
+
+use super::Query;
+
+/// Wraps a filter query and gives every matching document the same relevance score
+///
+/// Reference: [Constant score query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-constant-score-query.html)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConstantScore {
+    filter: Box<Query>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl ConstantScore {
+    pub fn new(filter: Query) -> Self {
+        Self {
+            filter: Box::new(filter),
+            boost: Default::default(),
+        }
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
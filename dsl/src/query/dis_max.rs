@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    types::EqualsToDefault,
+};
+
+use super::Query;
+
+/// Build a dis_max Query
+///
+/// Reference: [Disjunction max query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-dis-max-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     clause,
+///     query::{dis_max::DisMax, Query},
+/// };
+///
+/// let q = Query::DisMax(
+///     DisMax::new(clause!(Term, "title", "Quick pets"))
+///         .query(clause!(Term, "body", "Quick pets"))
+///         .tie_breaker(0.7)
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DisMax {
+    queries: Vec<Query>,
+    tie_breaker: Option<f32>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl DisMax {
+    pub fn new(query: Query) -> Self {
+        Self {
+            queries: vec![query],
+            tie_breaker: None,
+            boost: Default::default(),
+        }
+    }
+
+    pub fn query(mut self, query: Query) -> Self {
+        self.queries.push(query);
+
+        self
+    }
+
+    pub fn tie_breaker(mut self, v: f32) -> Self {
+        self.tie_breaker = Some(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
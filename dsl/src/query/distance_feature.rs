@@ -0,0 +1,165 @@
+use std::borrow::Cow;
+use serde::{
+    de::{self, value::StrDeserializer, IntoDeserializer},
+    Deserialize,
+    Serialize,
+};
+use crate::{
+    boost::Boost,
+    field::Field,
+    types::{
+        geo::{Distance, GeoPoint},
+        time::TimeValue,
+        EqualsToDefault,
+    },
+};
+
+/// Build a distance_feature Query, boosting documents closer to a date or a location
+///
+/// Reference: [Distance feature query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-distance-feature-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     query::{distance_feature::DistanceFeature, Query},
+///     types::{
+///         geo::{Distance, DistanceUnit, GeoPoint},
+///         time::{TimeUnit, TimeValue},
+///     },
+/// };
+///
+/// let q = Query::DistanceFeature(DistanceFeature::date("production_date", "now", TimeValue::new(7, TimeUnit::Days)));
+///
+/// let q = Query::DistanceFeature(
+///     DistanceFeature::geo("location", GeoPoint::array(40.71, -71.3), Distance::new(1000, DistanceUnit::Meters))
+/// );
+/// ```
+///
+/// When deserialized, the kind of origin is told by the `pivot`, a time value
+/// or a distance. As `m` is both minutes and meters, such a pivot only makes
+/// a geo origin out of a `lat,lon` or WKT string, or a non-string point, a
+/// geohash origin with a pivot in `m` being read as a date.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "DistanceFeatureRepr")]
+pub struct DistanceFeature {
+    field: Field,
+    #[serde(flatten)]
+    origin: Origin,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl DistanceFeature {
+    /// `origin` is a date or date math expression, e.g. `now-1h`
+    pub fn date(field: impl Into<Field>, origin: impl Into<Cow<'static, str>>, pivot: TimeValue) -> Self {
+        Self::with(
+            field,
+            Origin::Date {
+                origin: origin.into(),
+                pivot,
+            }
+        )
+    }
+
+    pub fn geo(field: impl Into<Field>, origin: GeoPoint, pivot: Distance) -> Self {
+        Self::with(
+            field,
+            Origin::Geo {
+                origin,
+                pivot,
+            }
+        )
+    }
+
+    fn with(field: impl Into<Field>, origin: Origin) -> Self {
+        Self {
+            field: field.into(),
+            origin,
+            boost: Default::default(),
+        }
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+enum Origin {
+    Date {
+        origin: Cow<'static, str>,
+        pivot: TimeValue,
+    },
+    Geo {
+        origin: GeoPoint,
+        pivot: Distance,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DistanceFeatureRepr {
+    field: Field,
+    origin: OriginRepr,
+    pivot: Cow<'static, str>,
+    #[serde(default)]
+    boost: Boost,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OriginRepr {
+    Text(Cow<'static, str>),
+    GeoPoint(GeoPoint),
+}
+
+impl TryFrom<DistanceFeatureRepr> for DistanceFeature {
+    type Error = String;
+
+    fn try_from(repr: DistanceFeatureRepr) -> Result<Self, Self::Error> {
+        let time_pivot = repr.pivot.parse::<TimeValue>().ok();
+        let distance_pivot = repr.pivot.parse::<Distance>().ok();
+
+        let origin = match (repr.origin, time_pivot, distance_pivot) {
+            (OriginRepr::GeoPoint(origin), _, Some(pivot)) => Origin::Geo {
+                origin,
+                pivot,
+            },
+            // A pivot in `m`, both a time value and a distance
+            (OriginRepr::Text(origin), Some(pivot), Some(distance_pivot)) => match parse_geo_point(&origin) {
+                Some(GeoPoint::Geohash(_)) | None => Origin::Date {
+                    origin,
+                    pivot,
+                },
+                Some(geo_origin) => Origin::Geo {
+                    origin: geo_origin,
+                    pivot: distance_pivot,
+                },
+            },
+            (OriginRepr::Text(origin), Some(pivot), None) => Origin::Date {
+                origin,
+                pivot,
+            },
+            (OriginRepr::Text(origin), None, Some(pivot)) => Origin::Geo {
+                origin: parse_geo_point(&origin).ok_or_else(|| format!("invalid geo point origin `{}`", origin))?,
+                pivot,
+            },
+            _ => return Err(format!("invalid pivot `{}` for the origin", repr.pivot)),
+        };
+
+        Ok(Self {
+            field: repr.field,
+            origin,
+            boost: repr.boost,
+        })
+    }
+}
+
+fn parse_geo_point(s: &str) -> Option<GeoPoint> {
+    let deserializer: StrDeserializer<'_, de::value::Error> = s.into_deserializer();
+
+    GeoPoint::deserialize(deserializer).ok()
+}
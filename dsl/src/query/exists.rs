@@ -1,15 +1,16 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use crate::field::Field;
 
-#[derive(Serialize)]
-pub struct Exists<'a> {
-    field: Field<'a>,
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Exists {
+    field: Field,
 }
 
-impl<'a> Exists<'a> {
-    pub fn new(field: Field<'a>) -> Self {
+impl Exists {
+    pub fn new(field: impl Into<Field>) -> Self {
         Self {
-            field,
+            field: field.into(),
         }
     }
 }
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use crate::{
+    boost::Boost,
+    field::Field,
+    types::EqualsToDefault,
+};
+
+use super::span::SpanQuery;
+
+/// Build a field_masking_span Query, letting a span query on another field
+/// be combined with span queries on `field` in `span_near` or `span_or`
+///
+/// Reference: [Span field masking query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-span-field-masking-query.html)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FieldMaskingSpan {
+    query: Box<SpanQuery>,
+    field: Field,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl FieldMaskingSpan {
+    pub fn new(query: SpanQuery, field: impl Into<Field>) -> Self {
+        Self {
+            query: Box::new(query),
+            field: field.into(),
+            boost: Default::default(),
+        }
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
@@ -0,0 +1,647 @@
+use std::borrow::Cow;
+use std::fmt;
+use macros::{DisplayCase, FromStrCase};
+use serde::{
+    de::{self, MapAccess, Visitor},
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
+use serde_with::{skip_serializing_none, DeserializeFromStr, SerializeDisplay};
+use crate::{
+    boost::Boost,
+    field::Field,
+    script::Script,
+    types::{
+        geo::{Distance, GeoPoint},
+        number::Number,
+        EqualsToDefault,
+    },
+};
+
+use super::Query;
+
+/// Build a function_score Query
+///
+/// Reference: [Function score query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-function-score-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     clause,
+///     query::{
+///         function_score::{
+///             BoostMode,
+///             Decay,
+///             FieldValueFactor,
+///             Function,
+///             FunctionScore,
+///             Modifier,
+///             ScoreMode,
+///         },
+///         Query,
+///         QueryValue,
+///     },
+/// };
+///
+/// let q = Query::FunctionScore(
+///     FunctionScore::new()
+///         .query(clause!(Match, "title", QueryValue::from("phone")))
+///         .function(
+///             Function::field_value_factor(
+///                 FieldValueFactor::new("popularity")
+///                     .factor(1.2)
+///                     .modifier(Modifier::Log1p)
+///                     .missing(1)
+///             )
+///         )
+///         .function(Function::gauss(Decay::new("published_at", "now", "10d").decay(0.5)).weight(2.0))
+///         .score_mode(ScoreMode::Sum)
+///         .boost_mode(BoostMode::Multiply)
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "FunctionScoreRepr")]
+pub struct FunctionScore {
+    query: Option<Box<Query>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    functions: Vec<Function>,
+    #[serde(default, skip_serializing_if = "ScoreMode::equals_to_default")]
+    score_mode: ScoreMode,
+    #[serde(default, skip_serializing_if = "BoostMode::equals_to_default")]
+    boost_mode: BoostMode,
+    max_boost: Option<f32>,
+    min_score: Option<f32>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl FunctionScore {
+    pub fn new() -> Self {
+        Self {
+            query: None,
+            functions: vec![],
+            score_mode: Default::default(),
+            boost_mode: Default::default(),
+            max_boost: None,
+            min_score: None,
+            boost: Default::default(),
+        }
+    }
+
+    pub fn query(mut self, query: Query) -> Self {
+        self.query = Some(Box::new(query));
+
+        self
+    }
+
+    pub fn function(mut self, function: Function) -> Self {
+        self.functions.push(function);
+
+        self
+    }
+
+    pub fn score_mode(mut self, v: ScoreMode) -> Self {
+        self.score_mode = v;
+
+        self
+    }
+
+    pub fn boost_mode(mut self, v: BoostMode) -> Self {
+        self.boost_mode = v;
+
+        self
+    }
+
+    pub fn max_boost(mut self, v: f32) -> Self {
+        self.max_boost = Some(v);
+
+        self
+    }
+
+    pub fn min_score(mut self, v: f32) -> Self {
+        self.min_score = Some(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+/// A single function may also be written next to the `query`, without
+/// `functions`, it is then read as the only entry of `functions`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FunctionScoreRepr {
+    query: Option<Box<Query>>,
+    #[serde(default)]
+    functions: Vec<Function>,
+    #[serde(default)]
+    score_mode: ScoreMode,
+    #[serde(default)]
+    boost_mode: BoostMode,
+    max_boost: Option<f32>,
+    min_score: Option<f32>,
+    #[serde(default)]
+    boost: Boost,
+    #[serde(flatten)]
+    score_function: ScoreFunctionRepr,
+    weight: Option<f32>,
+}
+
+impl TryFrom<FunctionScoreRepr> for FunctionScore {
+    type Error = String;
+
+    fn try_from(repr: FunctionScoreRepr) -> Result<Self, Self::Error> {
+        let mut functions = repr.functions;
+
+        if let Some(score_function) = repr.score_function.try_into_score_function()? {
+            if !functions.is_empty() {
+                return Err("expected either `functions` or a single score function, found both".to_owned());
+            }
+
+            functions.push(Function {
+                filter: None,
+                score_function: Some(score_function),
+                weight: repr.weight,
+            });
+        } else if let Some(weight) = repr.weight {
+            if !functions.is_empty() {
+                return Err("expected either `functions` or a single `weight`, found both".to_owned());
+            }
+
+            functions.push(Function::new().weight(weight));
+        }
+
+        Ok(Self {
+            query: repr.query,
+            functions,
+            score_mode: repr.score_mode,
+            boost_mode: repr.boost_mode,
+            max_boost: repr.max_boost,
+            min_score: repr.min_score,
+            boost: repr.boost,
+        })
+    }
+}
+
+/// An entry of `functions`, a score function applied to the documents matching its optional `filter`
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "FunctionRepr")]
+pub struct Function {
+    filter: Option<Query>,
+    #[serde(flatten)]
+    score_function: Option<ScoreFunction>,
+    weight: Option<f32>,
+}
+
+impl Function {
+    /// A function without a score function, scoring the matching documents by its `weight` only
+    pub fn new() -> Self {
+        Self {
+            filter: None,
+            score_function: None,
+            weight: None,
+        }
+    }
+
+    pub fn script_score(script: Script) -> Self {
+        Self::with(ScoreFunction::ScriptScore(ScriptScoreFunction { script }))
+    }
+
+    pub fn random_score(v: RandomScore) -> Self {
+        Self::with(ScoreFunction::RandomScore(v))
+    }
+
+    pub fn field_value_factor(v: FieldValueFactor) -> Self {
+        Self::with(ScoreFunction::FieldValueFactor(v))
+    }
+
+    pub fn gauss(v: Decay) -> Self {
+        Self::with(ScoreFunction::Gauss(v))
+    }
+
+    pub fn linear(v: Decay) -> Self {
+        Self::with(ScoreFunction::Linear(v))
+    }
+
+    pub fn exp(v: Decay) -> Self {
+        Self::with(ScoreFunction::Exp(v))
+    }
+
+    fn with(score_function: ScoreFunction) -> Self {
+        Self {
+            score_function: Some(score_function),
+            ..Self::new()
+        }
+    }
+
+    pub fn filter(mut self, v: Query) -> Self {
+        self.filter = Some(v);
+
+        self
+    }
+
+    pub fn weight(mut self, v: f32) -> Self {
+        self.weight = Some(v);
+
+        self
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FunctionRepr {
+    filter: Option<Query>,
+    #[serde(flatten)]
+    score_function: ScoreFunctionRepr,
+    weight: Option<f32>,
+}
+
+impl TryFrom<FunctionRepr> for Function {
+    type Error = String;
+
+    fn try_from(repr: FunctionRepr) -> Result<Self, Self::Error> {
+        Ok(Self {
+            filter: repr.filter,
+            score_function: repr.score_function.try_into_score_function()?,
+            weight: repr.weight,
+        })
+    }
+}
+
+/// The score functions are keyed by their type, each key is read on its own
+/// so that a malformed function is reported instead of being skipped.
+#[derive(Deserialize)]
+struct ScoreFunctionRepr {
+    script_score: Option<ScriptScoreFunction>,
+    random_score: Option<RandomScore>,
+    field_value_factor: Option<FieldValueFactor>,
+    gauss: Option<Decay>,
+    linear: Option<Decay>,
+    exp: Option<Decay>,
+}
+
+impl ScoreFunctionRepr {
+    fn try_into_score_function(self) -> Result<Option<ScoreFunction>, String> {
+        let functions = [
+            self.script_score.map(ScoreFunction::ScriptScore),
+            self.random_score.map(ScoreFunction::RandomScore),
+            self.field_value_factor.map(ScoreFunction::FieldValueFactor),
+            self.gauss.map(ScoreFunction::Gauss),
+            self.linear.map(ScoreFunction::Linear),
+            self.exp.map(ScoreFunction::Exp),
+        ];
+
+        let mut functions = functions.into_iter().flatten();
+        let function = functions.next();
+
+        if functions.next().is_some() {
+            return Err("expected a single score function, found more".to_owned());
+        }
+
+        Ok(function)
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ScoreFunction {
+    ScriptScore(ScriptScoreFunction),
+    RandomScore(RandomScore),
+    FieldValueFactor(FieldValueFactor),
+    Gauss(Decay),
+    Linear(Decay),
+    Exp(Decay),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScriptScoreFunction {
+    script: Script,
+}
+
+/// Reference: [Random](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-function-score-query.html#function-random)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RandomScore {
+    seed: Option<Number>,
+    field: Option<Field>,
+}
+
+impl RandomScore {
+    pub fn new() -> Self {
+        Self {
+            seed: None,
+            field: None,
+        }
+    }
+
+    pub fn seed(mut self, v: impl Into<Number>) -> Self {
+        self.seed = Some(v.into());
+
+        self
+    }
+
+    pub fn field(mut self, v: impl Into<Field>) -> Self {
+        self.field = Some(v.into());
+
+        self
+    }
+}
+
+/// Reference: [Field Value factor](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-function-score-query.html#function-field-value-factor)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FieldValueFactor {
+    field: Field,
+    factor: Option<f32>,
+    #[serde(default, skip_serializing_if = "Modifier::equals_to_default")]
+    modifier: Modifier,
+    missing: Option<Number>,
+}
+
+impl FieldValueFactor {
+    pub fn new(field: impl Into<Field>) -> Self {
+        Self {
+            field: field.into(),
+            factor: None,
+            modifier: Default::default(),
+            missing: None,
+        }
+    }
+
+    pub fn factor(mut self, v: f32) -> Self {
+        self.factor = Some(v);
+
+        self
+    }
+
+    pub fn modifier(mut self, v: Modifier) -> Self {
+        self.modifier = v;
+
+        self
+    }
+
+    pub fn missing(mut self, v: impl Into<Number>) -> Self {
+        self.missing = Some(v.into());
+
+        self
+    }
+}
+
+/// A `gauss`, `linear` or `exp` decay function over a numeric, date or geo field,
+/// serialized as `{"<field>": {"origin": ..., "scale": ...}}`
+///
+/// Reference: [Decay functions](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-function-score-query.html#function-decay)
+#[derive(Clone, Debug)]
+pub struct Decay {
+    field: Field,
+    opts: DecayOptions,
+    multi_value_mode: MultiValueMode,
+}
+
+impl Decay {
+    /// `origin` and `scale` are numbers for numeric fields, a date and a
+    /// time unit (`"10d"`) for date fields, or a point and a distance
+    /// (`"2km"`) for geo fields
+    pub fn new(field: impl Into<Field>, origin: impl Into<DecayValue>, scale: impl Into<DecayValue>) -> Self {
+        Self {
+            field: field.into(),
+            opts: DecayOptions {
+                origin: origin.into(),
+                scale: scale.into(),
+                offset: None,
+                decay: None,
+            },
+            multi_value_mode: Default::default(),
+        }
+    }
+
+    pub fn offset(mut self, v: impl Into<DecayValue>) -> Self {
+        self.opts.offset = Some(v.into());
+
+        self
+    }
+
+    pub fn decay(mut self, v: f32) -> Self {
+        self.opts.decay = Some(v);
+
+        self
+    }
+
+    pub fn multi_value_mode(mut self, v: MultiValueMode) -> Self {
+        self.multi_value_mode = v;
+
+        self
+    }
+}
+
+impl Serialize for Decay {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(None)?;
+        m.serialize_entry(&self.field, &self.opts)?;
+        if !self.multi_value_mode.equals_to_default() {
+            m.serialize_entry("multi_value_mode", &self.multi_value_mode)?;
+        }
+        m.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Decay {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        struct DecayVisitor;
+
+        impl<'de> Visitor<'de> for DecayVisitor {
+            type Value = Decay;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map with a single field entry and an optional `multi_value_mode`")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut entry = None;
+                let mut multi_value_mode = None;
+
+                while let Some(key) = map.next_key::<Cow<'static, str>>()? {
+                    if key == "multi_value_mode" {
+                        multi_value_mode = Some(map.next_value()?);
+                    } else if entry.is_some() {
+                        return Err(de::Error::custom("expected a single field entry, found more"));
+                    } else {
+                        entry = Some((Field::from(key.into_owned()), map.next_value()?));
+                    }
+                }
+
+                let (field, opts) = entry.ok_or_else(|| de::Error::missing_field("field"))?;
+
+                Ok(Decay {
+                    field,
+                    opts,
+                    multi_value_mode: multi_value_mode.unwrap_or_default(),
+                })
+            }
+        }
+
+        deserializer.deserialize_map(DecayVisitor)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DecayOptions {
+    origin: DecayValue,
+    scale: DecayValue,
+    offset: Option<DecayValue>,
+    decay: Option<f32>,
+}
+
+/// The `origin`, `scale` or `offset` of a decay function
+#[allow(dead_code)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DecayValue {
+    Number(Number),
+    /// A date, a time unit, a distance or a geo point written as a string
+    Text(Cow<'static, str>),
+    GeoPoint(GeoPoint),
+}
+
+impl From<GeoPoint> for DecayValue {
+    fn from(v: GeoPoint) -> Self {
+        Self::GeoPoint(v)
+    }
+}
+
+impl From<Distance> for DecayValue {
+    fn from(v: Distance) -> Self {
+        Self::Text(v.to_string().into())
+    }
+}
+
+impl From<&'static str> for DecayValue {
+    fn from(v: &'static str) -> Self {
+        Self::Text(v.into())
+    }
+}
+
+impl From<String> for DecayValue {
+    fn from(v: String) -> Self {
+        Self::Text(v.into())
+    }
+}
+
+impl<T> From<T> for DecayValue
+where
+    T: Into<Number>,
+{
+    fn from(v: T) -> Self {
+        Self::Number(v.into())
+    }
+}
+
+/// How the computed scores of the functions are combined
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
+#[display_case(case = "lowercase")]
+pub enum ScoreMode {
+    Multiply,
+    Sum,
+    Avg,
+    First,
+    Max,
+    Min,
+}
+
+impl Default for ScoreMode {
+    fn default() -> Self {
+        Self::Multiply
+    }
+}
+
+impl EqualsToDefault for ScoreMode {}
+
+/// How the combined score of the functions is combined with the score of the query
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
+#[display_case(case = "lowercase")]
+pub enum BoostMode {
+    Multiply,
+    Replace,
+    Sum,
+    Avg,
+    Max,
+    Min,
+}
+
+impl Default for BoostMode {
+    fn default() -> Self {
+        Self::Multiply
+    }
+}
+
+impl EqualsToDefault for BoostMode {}
+
+/// The function applied to the field value of a `field_value_factor`
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
+#[display_case(case = "lowercase")]
+pub enum Modifier {
+    None,
+    Log,
+    Log1p,
+    Log2p,
+    Ln,
+    Ln1p,
+    Ln2p,
+    Square,
+    Sqrt,
+    Reciprocal,
+}
+
+impl Default for Modifier {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+impl EqualsToDefault for Modifier {}
+
+/// How a decay function handles a field with multiple values
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
+#[display_case(case = "lowercase")]
+pub enum MultiValueMode {
+    Min,
+    Max,
+    Avg,
+    Sum,
+}
+
+impl Default for MultiValueMode {
+    fn default() -> Self {
+        Self::Min
+    }
+}
+
+impl EqualsToDefault for MultiValueMode {}
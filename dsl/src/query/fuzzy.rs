@@ -0,0 +1,134 @@
+use std::borrow::Cow;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    de::{deserialize_field_entry, ShortOrFull},
+    field::Field,
+    types::EqualsToDefault,
+};
+
+use super::r#match::{
+    FuzzyTranspositions,
+    MaxExpansions,
+    PrefixLength,
+};
+
+/// Reference: [Fuzzy query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-fuzzy-query.html)
+#[derive(Clone, Debug)]
+pub struct Fuzzy {
+    field: Field,
+    opts: FuzzyOptions,
+}
+
+impl Fuzzy {
+    pub fn new(field: impl Into<Field>, value: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            field: field.into(),
+            opts: FuzzyOptions::new(value.into()),
+        }
+    }
+
+    pub fn fuzziness(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.opts.fuzziness = Some(v.into());
+
+        self
+    }
+
+    pub fn max_expansions(mut self, v: i32) -> Self {
+        self.opts.max_expansions = MaxExpansions(v);
+
+        self
+    }
+
+    pub fn prefix_length(mut self, v: i32) -> Self {
+        self.opts.prefix_length = PrefixLength(v);
+
+        self
+    }
+
+    pub fn transpositions(mut self, v: bool) -> Self {
+        self.opts.transpositions = FuzzyTranspositions(v);
+
+        self
+    }
+
+    pub fn rewrite(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.opts.rewrite = Some(v.into());
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.opts.boost = Boost(v);
+
+        self
+    }
+}
+
+impl Serialize for Fuzzy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(Some(1))?;
+        m.serialize_entry(&self.field, &self.opts)?;
+        m.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Fuzzy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let (field, opts) = deserialize_field_entry::<_, ShortOrFull<Cow<'static, str>, FuzzyOptions>>(deserializer)?;
+
+        let opts = match opts {
+            ShortOrFull::Short(value) => FuzzyOptions::new(value),
+            ShortOrFull::Full(opts) => opts,
+        };
+
+        Ok(Self {
+            field,
+            opts,
+        })
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FuzzyOptions {
+    value: Cow<'static, str>,
+    fuzziness: Option<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "MaxExpansions::equals_to_default")]
+    max_expansions: MaxExpansions,
+    #[serde(default, skip_serializing_if = "PrefixLength::equals_to_default")]
+    prefix_length: PrefixLength,
+    #[serde(default, skip_serializing_if = "FuzzyTranspositions::equals_to_default")]
+    transpositions: FuzzyTranspositions,
+    rewrite: Option<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl FuzzyOptions {
+    fn new(value: Cow<'static, str>) -> Self {
+        Self {
+            value,
+            fuzziness: None,
+            max_expansions: Default::default(),
+            prefix_length: Default::default(),
+            transpositions: Default::default(),
+            rewrite: None,
+            boost: Default::default(),
+        }
+    }
+}
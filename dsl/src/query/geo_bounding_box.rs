@@ -0,0 +1,118 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use crate::{
+    boost::Boost,
+    field::{Field, FieldEntry},
+    ignore_unmapped::IgnoreUnmapped,
+    types::{
+        geo::{GeoPoint, ValidationMethod},
+        EqualsToDefault,
+    },
+};
+
+/// Build a geo_bounding_box Query, matching the geo points within a bounding box
+///
+/// Reference: [Geo-bounding box query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-geo-bounding-box-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     query::{
+///         geo_bounding_box::{BoundingBox, GeoBoundingBox},
+///         Query,
+///     },
+///     types::geo::GeoPoint,
+/// };
+///
+/// let q = Query::GeoBoundingBox(
+///     GeoBoundingBox::new(
+///         "pin.location",
+///         BoundingBox::corners(GeoPoint::lat_lon(40.73, -74.1), GeoPoint::lat_lon(40.01, -71.12))
+///     )
+/// );
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GeoBoundingBox {
+    #[serde(flatten)]
+    bounding_box: FieldEntry<BoundingBox>,
+    #[serde(default, skip_serializing_if = "ValidationMethod::equals_to_default")]
+    validation_method: ValidationMethod,
+    #[serde(default, skip_serializing_if = "IgnoreUnmapped::equals_to_default")]
+    ignore_unmapped: IgnoreUnmapped,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl GeoBoundingBox {
+    pub fn new(field: impl Into<Field>, bounding_box: BoundingBox) -> Self {
+        Self {
+            bounding_box: FieldEntry::new(field, bounding_box),
+            validation_method: Default::default(),
+            ignore_unmapped: Default::default(),
+            boost: Default::default(),
+        }
+    }
+
+    pub fn validation_method(mut self, v: ValidationMethod) -> Self {
+        self.validation_method = v;
+
+        self
+    }
+
+    pub fn ignore_unmapped(mut self, v: bool) -> Self {
+        self.ignore_unmapped = IgnoreUnmapped(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+/// The bounding box, given by two corners, by its four sides or as a WKT `BBOX`
+#[allow(dead_code)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BoundingBox {
+    Corners {
+        top_left: GeoPoint,
+        bottom_right: GeoPoint,
+    },
+    Vertices {
+        top: f64,
+        left: f64,
+        bottom: f64,
+        right: f64,
+    },
+    Wkt {
+        wkt: Cow<'static, str>,
+    },
+}
+
+impl BoundingBox {
+    pub fn corners(top_left: GeoPoint, bottom_right: GeoPoint) -> Self {
+        Self::Corners {
+            top_left,
+            bottom_right,
+        }
+    }
+
+    pub fn vertices(top: f64, left: f64, bottom: f64, right: f64) -> Self {
+        Self::Vertices {
+            top,
+            left,
+            bottom,
+            right,
+        }
+    }
+
+    /// e.g. `"BBOX (-74.1, -71.12, 40.73, 40.01)"`
+    pub fn wkt(v: impl Into<Cow<'static, str>>) -> Self {
+        Self::Wkt {
+            wkt: v.into(),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use macros::{DisplayCase, FromStrCase};
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use crate::{
+    boost::Boost,
+    field::{Field, FieldEntry},
+    ignore_unmapped::IgnoreUnmapped,
+    types::{
+        geo::{Distance, GeoPoint, ValidationMethod},
+        EqualsToDefault,
+    },
+};
+
+/// Build a geo_distance Query, matching the geo points within a distance of a central point
+///
+/// Reference: [Geo-distance query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-geo-distance-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     query::{geo_distance::GeoDistance, Query},
+///     types::geo::{Distance, DistanceUnit, GeoPoint},
+/// };
+///
+/// let q = Query::GeoDistance(
+///     GeoDistance::new("pin.location", GeoPoint::lat_lon(40.0, -70.0), Distance::new(200, DistanceUnit::Kilometers))
+/// );
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GeoDistance {
+    #[serde(flatten)]
+    point: FieldEntry<GeoPoint>,
+    distance: Distance,
+    #[serde(default, skip_serializing_if = "DistanceType::equals_to_default")]
+    distance_type: DistanceType,
+    #[serde(default, skip_serializing_if = "ValidationMethod::equals_to_default")]
+    validation_method: ValidationMethod,
+    #[serde(default, skip_serializing_if = "IgnoreUnmapped::equals_to_default")]
+    ignore_unmapped: IgnoreUnmapped,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl GeoDistance {
+    pub fn new(field: impl Into<Field>, point: GeoPoint, distance: Distance) -> Self {
+        Self {
+            point: FieldEntry::new(field, point),
+            distance,
+            distance_type: Default::default(),
+            validation_method: Default::default(),
+            ignore_unmapped: Default::default(),
+            boost: Default::default(),
+        }
+    }
+
+    pub fn distance_type(mut self, v: DistanceType) -> Self {
+        self.distance_type = v;
+
+        self
+    }
+
+    pub fn validation_method(mut self, v: ValidationMethod) -> Self {
+        self.validation_method = v;
+
+        self
+    }
+
+    pub fn ignore_unmapped(mut self, v: bool) -> Self {
+        self.ignore_unmapped = IgnoreUnmapped(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+/// How to compute the distance, `plane` is faster but inaccurate on long distances and close to the poles
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
+#[display_case(case = "lowercase")]
+pub enum DistanceType {
+    Arc,
+    Plane,
+}
+
+impl Default for DistanceType {
+    fn default() -> Self {
+        Self::Arc
+    }
+}
+
+impl EqualsToDefault for DistanceType {}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use crate::{
+    boost::Boost,
+    field::{Field, FieldEntry},
+    ignore_unmapped::IgnoreUnmapped,
+    types::{
+        geo::{GeoPoint, ValidationMethod},
+        EqualsToDefault,
+    },
+};
+
+/// Build a geo_polygon Query, matching the geo points within a polygon
+///
+/// Reference: [Geo-polygon query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-geo-polygon-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     query::{geo_polygon::GeoPolygon, Query},
+///     types::geo::GeoPoint,
+/// };
+///
+/// let q = Query::GeoPolygon(
+///     GeoPolygon::new(
+///         "person.location",
+///         [
+///             GeoPoint::lat_lon(40.0, -70.0),
+///             GeoPoint::lat_lon(30.0, -80.0),
+///             GeoPoint::lat_lon(20.0, -90.0),
+///         ]
+///     )
+/// );
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GeoPolygon {
+    #[serde(flatten)]
+    polygon: FieldEntry<Polygon>,
+    #[serde(default, skip_serializing_if = "ValidationMethod::equals_to_default")]
+    validation_method: ValidationMethod,
+    #[serde(default, skip_serializing_if = "IgnoreUnmapped::equals_to_default")]
+    ignore_unmapped: IgnoreUnmapped,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl GeoPolygon {
+    pub fn new(field: impl Into<Field>, points: impl IntoIterator<Item = GeoPoint>) -> Self {
+        Self {
+            polygon: FieldEntry::new(
+                field,
+                Polygon {
+                    points: points.into_iter().collect(),
+                }
+            ),
+            validation_method: Default::default(),
+            ignore_unmapped: Default::default(),
+            boost: Default::default(),
+        }
+    }
+
+    pub fn validation_method(mut self, v: ValidationMethod) -> Self {
+        self.validation_method = v;
+
+        self
+    }
+
+    pub fn ignore_unmapped(mut self, v: bool) -> Self {
+        self.ignore_unmapped = IgnoreUnmapped(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Polygon {
+    points: Vec<GeoPoint>,
+}
@@ -0,0 +1,198 @@
+use std::borrow::Cow;
+use macros::{DisplayCase, FromStrCase};
+use serde::{Deserialize, Serialize};
+use serde_with::{skip_serializing_none, DeserializeFromStr, SerializeDisplay};
+use crate::{
+    boost::Boost,
+    field::{Field, FieldEntry},
+    ignore_unmapped::IgnoreUnmapped,
+    types::EqualsToDefault,
+};
+
+/// Build a geo_shape Query, matching the geo shapes or points related to a shape
+///
+/// Reference: [Geo-shape query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-geo-shape-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::query::{
+///     geo_shape::{GeoShape, Shape, SpatialRelation},
+///     Query,
+/// };
+///
+/// let q = Query::GeoShape(
+///     GeoShape::new(
+///         "location",
+///         Shape::Envelope {
+///             coordinates: [[13.0, 53.0], [14.0, 52.0]],
+///         }
+///     )
+///     .relation(SpatialRelation::Within)
+/// );
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GeoShape {
+    #[serde(flatten)]
+    shape: FieldEntry<GeoShapeOptions>,
+    #[serde(default, skip_serializing_if = "IgnoreUnmapped::equals_to_default")]
+    ignore_unmapped: IgnoreUnmapped,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl GeoShape {
+    pub fn new(field: impl Into<Field>, shape: Shape) -> Self {
+        Self::with(field, ShapeSource::Shape(shape))
+    }
+
+    /// Use a shape indexed in another index
+    pub fn indexed(field: impl Into<Field>, indexed_shape: IndexedShape) -> Self {
+        Self::with(field, ShapeSource::IndexedShape(indexed_shape))
+    }
+
+    fn with(field: impl Into<Field>, source: ShapeSource) -> Self {
+        Self {
+            shape: FieldEntry::new(
+                field,
+                GeoShapeOptions {
+                    source,
+                    relation: Default::default(),
+                }
+            ),
+            ignore_unmapped: Default::default(),
+            boost: Default::default(),
+        }
+    }
+
+    pub fn relation(mut self, v: SpatialRelation) -> Self {
+        self.shape.value.relation = v;
+
+        self
+    }
+
+    pub fn ignore_unmapped(mut self, v: bool) -> Self {
+        self.ignore_unmapped = IgnoreUnmapped(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GeoShapeOptions {
+    #[serde(flatten)]
+    source: ShapeSource,
+    #[serde(default, skip_serializing_if = "SpatialRelation::equals_to_default")]
+    relation: SpatialRelation,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ShapeSource {
+    Shape(Shape),
+    IndexedShape(IndexedShape),
+}
+
+/// A GeoJSON geometry, or an `envelope` given by its top left and bottom right corners.
+/// Coordinates are `[lon, lat]`. Serialized with the lowercase types, both
+/// these and the GeoJSON spelling, e.g. `LineString`, are accepted.
+///
+/// Reference: [Input Structure](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/geo-shape.html#input-structure)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Shape {
+    #[serde(alias = "Point")]
+    Point {
+        coordinates: [f64; 2],
+    },
+    #[serde(alias = "LineString")]
+    LineString {
+        coordinates: Vec<[f64; 2]>,
+    },
+    #[serde(alias = "Polygon")]
+    Polygon {
+        coordinates: Vec<Vec<[f64; 2]>>,
+    },
+    #[serde(alias = "MultiPoint")]
+    MultiPoint {
+        coordinates: Vec<[f64; 2]>,
+    },
+    #[serde(alias = "MultiLineString")]
+    MultiLineString {
+        coordinates: Vec<Vec<[f64; 2]>>,
+    },
+    #[serde(alias = "MultiPolygon")]
+    MultiPolygon {
+        coordinates: Vec<Vec<Vec<[f64; 2]>>>,
+    },
+    #[serde(alias = "GeometryCollection")]
+    GeometryCollection {
+        geometries: Vec<Shape>,
+    },
+    #[serde(alias = "Envelope")]
+    Envelope {
+        coordinates: [[f64; 2]; 2],
+    },
+}
+
+/// A shape indexed in another index
+///
+/// Reference: [Pre-Indexed Shape](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-geo-shape-query.html#_pre_indexed_shape)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IndexedShape {
+    index: Cow<'static, str>,
+    id: Cow<'static, str>,
+    path: Option<Cow<'static, str>>,
+    routing: Option<Cow<'static, str>>,
+}
+
+impl IndexedShape {
+    pub fn new(index: impl Into<Cow<'static, str>>, id: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            index: index.into(),
+            id: id.into(),
+            path: None,
+            routing: None,
+        }
+    }
+
+    /// The field holding the shape, defaults to `shape`
+    pub fn path(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.path = Some(v.into());
+
+        self
+    }
+
+    pub fn routing(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.routing = Some(v.into());
+
+        self
+    }
+}
+
+/// The spatial relation between the query shape and the indexed shapes, defaults to `intersects`
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
+#[display_case(case = "lowercase")]
+pub enum SpatialRelation {
+    Intersects,
+    Disjoint,
+    Within,
+    Contains,
+}
+
+impl Default for SpatialRelation {
+    fn default() -> Self {
+        Self::Intersects
+    }
+}
+
+impl EqualsToDefault for SpatialRelation {}
@@ -0,0 +1,95 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    ignore_unmapped::IgnoreUnmapped,
+    types::EqualsToDefault,
+};
+
+use super::{
+    inner_hits::InnerHits,
+    nested::ScoreMode,
+    Query,
+};
+
+/// Build a has_child Query, matching the parent documents of the child documents matching `query`
+///
+/// Reference: [Has child query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-has-child-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::query::{
+///     has_child::HasChild,
+///     inner_hits::InnerHits,
+///     match_all::MatchAll,
+///     nested::ScoreMode,
+///     Query,
+/// };
+///
+/// let q = Query::HasChild(
+///     HasChild::new("line_item", Query::MatchAll(MatchAll::new()))
+///         .score_mode(ScoreMode::Max)
+///         .min_children(2)
+///         .max_children(10)
+///         .inner_hits(InnerHits::new().size(3))
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HasChild {
+    r#type: Cow<'static, str>,
+    query: Box<Query>,
+    /// `none` by default for `has_child`, unlike `nested`, so it is
+    /// serialized whenever it is set
+    score_mode: Option<ScoreMode>,
+    min_children: Option<i32>,
+    max_children: Option<i32>,
+    #[serde(default, skip_serializing_if = "IgnoreUnmapped::equals_to_default")]
+    ignore_unmapped: IgnoreUnmapped,
+    inner_hits: Option<InnerHits>,
+}
+
+impl HasChild {
+    pub fn new(r#type: impl Into<Cow<'static, str>>, query: Query) -> Self {
+        Self {
+            r#type: r#type.into(),
+            query: Box::new(query),
+            score_mode: None,
+            min_children: None,
+            max_children: None,
+            ignore_unmapped: Default::default(),
+            inner_hits: None,
+        }
+    }
+
+    pub fn score_mode(mut self, v: ScoreMode) -> Self {
+        self.score_mode = Some(v);
+
+        self
+    }
+
+    pub fn min_children(mut self, v: i32) -> Self {
+        self.min_children = Some(v);
+
+        self
+    }
+
+    pub fn max_children(mut self, v: i32) -> Self {
+        self.max_children = Some(v);
+
+        self
+    }
+
+    pub fn ignore_unmapped(mut self, v: bool) -> Self {
+        self.ignore_unmapped = IgnoreUnmapped(v);
+
+        self
+    }
+
+    pub fn inner_hits(mut self, v: InnerHits) -> Self {
+        self.inner_hits = Some(v);
+
+        self
+    }
+}
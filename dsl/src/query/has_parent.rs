@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    ignore_unmapped::IgnoreUnmapped,
+    types::EqualsToDefault,
+};
+
+use super::{inner_hits::InnerHits, Query};
+
+/// Build a has_parent Query, matching the child documents of the parent documents matching `query`
+///
+/// Reference: [Has parent query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-has-parent-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     clause,
+///     query::{has_parent::HasParent, inner_hits::InnerHits, Query},
+/// };
+///
+/// let q = Query::HasParent(
+///     HasParent::new("order", clause!(Term, "status", "shipped"))
+///         .score(true)
+///         .inner_hits(InnerHits::new())
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HasParent {
+    parent_type: Cow<'static, str>,
+    query: Box<Query>,
+    #[serde(default, skip_serializing_if = "Score::equals_to_default")]
+    score: Score,
+    #[serde(default, skip_serializing_if = "IgnoreUnmapped::equals_to_default")]
+    ignore_unmapped: IgnoreUnmapped,
+    inner_hits: Option<InnerHits>,
+}
+
+impl HasParent {
+    pub fn new(parent_type: impl Into<Cow<'static, str>>, query: Query) -> Self {
+        Self {
+            parent_type: parent_type.into(),
+            query: Box::new(query),
+            score: Default::default(),
+            ignore_unmapped: Default::default(),
+            inner_hits: None,
+        }
+    }
+
+    pub fn score(mut self, v: bool) -> Self {
+        self.score = Score(v);
+
+        self
+    }
+
+    pub fn ignore_unmapped(mut self, v: bool) -> Self {
+        self.ignore_unmapped = IgnoreUnmapped(v);
+
+        self
+    }
+
+    pub fn inner_hits(mut self, v: InnerHits) -> Self {
+        self.inner_hits = Some(v);
+
+        self
+    }
+}
+
+/// Aggregate the relevance score of the matching parent into its child documents. Defaults to false.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Score(bool);
+
+impl Default for Score {
+    fn default() -> Self {
+        Self(false)
+    }
+}
+
+impl EqualsToDefault for Score {}
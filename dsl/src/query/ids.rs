@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use crate::{
+    boost::Boost,
+    types::EqualsToDefault,
+};
+
+/// Reference: [IDs query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-ids-query.html)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Ids {
+    values: Vec<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl Ids {
+    pub fn new<T>(values: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        Self {
+            values: values.into_iter().map(Into::into).collect(),
+            boost: Default::default(),
+        }
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
@@ -0,0 +1,53 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::sort::Sort;
+
+/// Returns the nested, child or parent documents which caused a hit to match,
+/// requested by the `nested`, `has_child` and `has_parent` queries
+///
+/// Reference: [Retrieve inner hits](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/inner-hits.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InnerHits {
+    name: Option<Cow<'static, str>>,
+    from: Option<i64>,
+    size: Option<i64>,
+    sort: Option<Sort>,
+}
+
+impl InnerHits {
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            from: None,
+            size: None,
+            sort: None,
+        }
+    }
+
+    pub fn name(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(v.into());
+
+        self
+    }
+
+    pub fn from(mut self, v: i64) -> Self {
+        self.from = Some(v);
+
+        self
+    }
+
+    pub fn size(mut self, v: i64) -> Self {
+        self.size = Some(v);
+
+        self
+    }
+
+    pub fn sort(mut self, v: Sort) -> Self {
+        self.sort = Some(v);
+
+        self
+    }
+}
@@ -0,0 +1,433 @@
+use std::borrow::Cow;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    de::deserialize_field_entry,
+    field::Field,
+    script::Script,
+    types::EqualsToDefault,
+};
+
+/// Build an intervals Query, matching documents on the ordering and proximity of terms
+///
+/// Reference: [Intervals query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-intervals-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::query::{
+///     intervals::{
+///         AllOfRule,
+///         AnyOfRule,
+///         Intervals,
+///         IntervalsFilter,
+///         IntervalsRule,
+///         MatchRule,
+///     },
+///     Query,
+/// };
+///
+/// let q = Query::Intervals(
+///     Intervals::new(
+///         "my_text",
+///         IntervalsRule::AllOf(
+///             AllOfRule::new()
+///                 .interval(IntervalsRule::Match(MatchRule::new("my favorite food").max_gaps(0).ordered(true)))
+///                 .interval(
+///                     IntervalsRule::AnyOf(
+///                         AnyOfRule::new()
+///                             .interval(IntervalsRule::Match(MatchRule::new("hot water")))
+///                             .interval(IntervalsRule::Match(MatchRule::new("cold porridge")))
+///                     )
+///                 )
+///                 .ordered(true)
+///                 .filter(IntervalsFilter::not_containing(IntervalsRule::Match(MatchRule::new("salty"))))
+///         )
+///     )
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Intervals {
+    field: Field,
+    opts: IntervalsOptions,
+}
+
+impl Intervals {
+    pub fn new(field: impl Into<Field>, rule: IntervalsRule) -> Self {
+        Self {
+            field: field.into(),
+            opts: IntervalsOptions {
+                rule,
+                boost: Default::default(),
+            },
+        }
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.opts.boost = Boost(v);
+
+        self
+    }
+}
+
+impl Serialize for Intervals {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(Some(1))?;
+        m.serialize_entry(&self.field, &self.opts)?;
+        m.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Intervals {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let (field, opts) = deserialize_field_entry(deserializer)?;
+
+        Ok(Self {
+            field,
+            opts,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IntervalsOptions {
+    #[serde(flatten)]
+    rule: IntervalsRule,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+/// A rule producing the intervals to match, `all_of` and `any_of` combine further rules
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntervalsRule {
+    Match(MatchRule),
+    Prefix(PrefixRule),
+    Wildcard(WildcardRule),
+    Fuzzy(FuzzyRule),
+    AllOf(AllOfRule),
+    AnyOf(AnyOfRule),
+}
+
+/// Reference: [match rule parameters](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-intervals-query.html#intervals-match)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MatchRule {
+    query: Cow<'static, str>,
+    max_gaps: Option<i32>,
+    ordered: Option<bool>,
+    analyzer: Option<Cow<'static, str>>,
+    filter: Option<IntervalsFilter>,
+    use_field: Option<Field>,
+}
+
+impl MatchRule {
+    pub fn new(query: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            query: query.into(),
+            max_gaps: None,
+            ordered: None,
+            analyzer: None,
+            filter: None,
+            use_field: None,
+        }
+    }
+
+    /// Maximum number of positions between the matching terms, defaults to -1, i.e. unlimited
+    pub fn max_gaps(mut self, v: i32) -> Self {
+        self.max_gaps = Some(v);
+
+        self
+    }
+
+    pub fn ordered(mut self, v: bool) -> Self {
+        self.ordered = Some(v);
+
+        self
+    }
+
+    pub fn analyzer(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.analyzer = Some(v.into());
+
+        self
+    }
+
+    pub fn filter(mut self, v: IntervalsFilter) -> Self {
+        self.filter = Some(v);
+
+        self
+    }
+
+    pub fn use_field(mut self, v: impl Into<Field>) -> Self {
+        self.use_field = Some(v.into());
+
+        self
+    }
+}
+
+/// Reference: [prefix rule parameters](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-intervals-query.html#intervals-prefix)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PrefixRule {
+    prefix: Cow<'static, str>,
+    analyzer: Option<Cow<'static, str>>,
+    use_field: Option<Field>,
+}
+
+impl PrefixRule {
+    pub fn new(prefix: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            analyzer: None,
+            use_field: None,
+        }
+    }
+
+    pub fn analyzer(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.analyzer = Some(v.into());
+
+        self
+    }
+
+    pub fn use_field(mut self, v: impl Into<Field>) -> Self {
+        self.use_field = Some(v.into());
+
+        self
+    }
+}
+
+/// Reference: [wildcard rule parameters](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-intervals-query.html#intervals-wildcard)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WildcardRule {
+    pattern: Cow<'static, str>,
+    analyzer: Option<Cow<'static, str>>,
+    use_field: Option<Field>,
+}
+
+impl WildcardRule {
+    pub fn new(pattern: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            analyzer: None,
+            use_field: None,
+        }
+    }
+
+    pub fn analyzer(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.analyzer = Some(v.into());
+
+        self
+    }
+
+    pub fn use_field(mut self, v: impl Into<Field>) -> Self {
+        self.use_field = Some(v.into());
+
+        self
+    }
+}
+
+/// Reference: [fuzzy rule parameters](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-intervals-query.html#intervals-fuzzy)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FuzzyRule {
+    term: Cow<'static, str>,
+    prefix_length: Option<i32>,
+    transpositions: Option<bool>,
+    fuzziness: Option<Cow<'static, str>>,
+    analyzer: Option<Cow<'static, str>>,
+    use_field: Option<Field>,
+}
+
+impl FuzzyRule {
+    pub fn new(term: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            term: term.into(),
+            prefix_length: None,
+            transpositions: None,
+            fuzziness: None,
+            analyzer: None,
+            use_field: None,
+        }
+    }
+
+    pub fn prefix_length(mut self, v: i32) -> Self {
+        self.prefix_length = Some(v);
+
+        self
+    }
+
+    pub fn transpositions(mut self, v: bool) -> Self {
+        self.transpositions = Some(v);
+
+        self
+    }
+
+    pub fn fuzziness(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.fuzziness = Some(v.into());
+
+        self
+    }
+
+    pub fn analyzer(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.analyzer = Some(v.into());
+
+        self
+    }
+
+    pub fn use_field(mut self, v: impl Into<Field>) -> Self {
+        self.use_field = Some(v.into());
+
+        self
+    }
+}
+
+/// Reference: [all_of rule parameters](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-intervals-query.html#intervals-all_of)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AllOfRule {
+    intervals: Vec<IntervalsRule>,
+    max_gaps: Option<i32>,
+    ordered: Option<bool>,
+    filter: Option<IntervalsFilter>,
+}
+
+impl AllOfRule {
+    pub fn new() -> Self {
+        Self {
+            intervals: vec![],
+            max_gaps: None,
+            ordered: None,
+            filter: None,
+        }
+    }
+
+    pub fn interval(mut self, rule: IntervalsRule) -> Self {
+        self.intervals.push(rule);
+
+        self
+    }
+
+    pub fn max_gaps(mut self, v: i32) -> Self {
+        self.max_gaps = Some(v);
+
+        self
+    }
+
+    pub fn ordered(mut self, v: bool) -> Self {
+        self.ordered = Some(v);
+
+        self
+    }
+
+    pub fn filter(mut self, v: IntervalsFilter) -> Self {
+        self.filter = Some(v);
+
+        self
+    }
+}
+
+/// Reference: [any_of rule parameters](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-intervals-query.html#intervals-any_of)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnyOfRule {
+    intervals: Vec<IntervalsRule>,
+    filter: Option<IntervalsFilter>,
+}
+
+impl AnyOfRule {
+    pub fn new() -> Self {
+        Self {
+            intervals: vec![],
+            filter: None,
+        }
+    }
+
+    pub fn interval(mut self, rule: IntervalsRule) -> Self {
+        self.intervals.push(rule);
+
+        self
+    }
+
+    pub fn filter(mut self, v: IntervalsFilter) -> Self {
+        self.filter = Some(v);
+
+        self
+    }
+}
+
+/// Filters the intervals produced by a rule, either by their relation to the
+/// intervals of another rule or with a script
+///
+/// Reference: [filter parameters](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-intervals-query.html#interval_filter)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntervalsFilter {
+    After(Box<IntervalsRule>),
+    Before(Box<IntervalsRule>),
+    ContainedBy(Box<IntervalsRule>),
+    Containing(Box<IntervalsRule>),
+    NotContainedBy(Box<IntervalsRule>),
+    NotContaining(Box<IntervalsRule>),
+    NotOverlapping(Box<IntervalsRule>),
+    Overlapping(Box<IntervalsRule>),
+    Script(Script),
+}
+
+impl IntervalsFilter {
+    pub fn after(rule: IntervalsRule) -> Self {
+        Self::After(Box::new(rule))
+    }
+
+    pub fn before(rule: IntervalsRule) -> Self {
+        Self::Before(Box::new(rule))
+    }
+
+    pub fn contained_by(rule: IntervalsRule) -> Self {
+        Self::ContainedBy(Box::new(rule))
+    }
+
+    pub fn containing(rule: IntervalsRule) -> Self {
+        Self::Containing(Box::new(rule))
+    }
+
+    pub fn not_contained_by(rule: IntervalsRule) -> Self {
+        Self::NotContainedBy(Box::new(rule))
+    }
+
+    pub fn not_containing(rule: IntervalsRule) -> Self {
+        Self::NotContaining(Box::new(rule))
+    }
+
+    pub fn not_overlapping(rule: IntervalsRule) -> Self {
+        Self::NotOverlapping(Box::new(rule))
+    }
+
+    pub fn overlapping(rule: IntervalsRule) -> Self {
+        Self::Overlapping(Box::new(rule))
+    }
+
+    /// A script over `interval.start`, `interval.end` and `interval.gaps`
+    pub fn script(script: Script) -> Self {
+        Self::Script(script)
+    }
+}
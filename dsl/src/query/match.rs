@@ -1,98 +1,108 @@
 use std::borrow::Cow;
-use macros::DisplayCase;
-use serde::{Serialize, Serializer, ser::SerializeMap};
-use serde_with::{skip_serializing_none, SerializeDisplay};
+use macros::{DisplayCase, FromStrCase};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, ser::SerializeMap};
+use serde_with::{skip_serializing_none, DeserializeFromStr, SerializeDisplay};
 use crate::{
+    boost::Boost,
+    de::{deserialize_field_entry, ShortOrFull},
     field::Field,
     types::EqualsToDefault,
 };
 
 use super::QueryValue;
 
-pub struct Match<'a> {
-    field: Field<'a>,
-    inner: Inner<'a>,
+#[derive(Clone, Debug)]
+pub struct Match {
+    field: Field,
+    inner: Inner,
 }
 
-impl<'a> Match<'a> {
-    pub fn new(field: Field<'a>, value: QueryValue) -> Self {
+impl Match {
+    pub fn new(field: impl Into<Field>, value: QueryValue) -> Self {
         Self {
-            field,
+            field: field.into(),
             inner: Inner {
                 query: value,
                 opts: Default::default(),
+                boost: Default::default(),
             },
         }
     }
 
-    pub fn analyzer(&mut self, v: &'a str) -> &mut Self {
+    pub fn analyzer(mut self, v: impl Into<Cow<'static, str>>) -> Self {
         self.inner.opts.analyzer = Some(v.into());
 
         self
     }
 
-    pub fn auto_generate_synonyms_phrase_query(&mut self, v: bool) -> &mut Self {
+    pub fn auto_generate_synonyms_phrase_query(mut self, v: bool) -> Self {
         self.inner.opts.auto_generate_synonyms_phrase_query = AutoGenerateSynonymsPhraseQuery(v);
 
         self
     }
 
-    pub fn fuzziness(&mut self, v: &'a str) -> &mut Self {
+    pub fn fuzziness(mut self, v: impl Into<Cow<'static, str>>) -> Self {
         self.inner.opts.fuzziness = Some(v.into());
 
         self
     }
 
-    pub fn max_expansions(&mut self, v: i32) -> &mut Self {
+    pub fn max_expansions(mut self, v: i32) -> Self {
         self.inner.opts.max_expansions = MaxExpansions(v);
 
         self
     }
 
-    pub fn prefix_length(&mut self, v: i32) -> &mut Self {
+    pub fn prefix_length(mut self, v: i32) -> Self {
         self.inner.opts.prefix_length = PrefixLength(v);
 
         self
     }
 
-    pub fn fuzzy_transpositions(&mut self, v: bool) -> &mut Self {
+    pub fn fuzzy_transpositions(mut self, v: bool) -> Self {
         self.inner.opts.fuzzy_transpositions = FuzzyTranspositions(v);
 
         self
     }
 
-    pub fn fuzzy_rewrite(&mut self, v: &'a str) -> &mut Self {
+    pub fn fuzzy_rewrite(mut self, v: impl Into<Cow<'static, str>>) -> Self {
         self.inner.opts.fuzzy_rewrite = Some(v.into());
 
         self
     }
 
-    pub fn lenient(&mut self, v: bool) -> &mut Self {
+    pub fn lenient(mut self, v: bool) -> Self {
         self.inner.opts.lenient = Lenient(v);
 
         self
     }
 
-    pub fn operator(&mut self, v: Operator) -> &mut Self {
+    pub fn operator(mut self, v: Operator) -> Self {
         self.inner.opts.operator = v;
 
         self
     }
 
-    pub fn minimum_should_match(&mut self, v: &'a str) -> &mut Self {
+    pub fn minimum_should_match(mut self, v: impl Into<Cow<'static, str>>) -> Self {
         self.inner.opts.minimum_should_match = Some(v.into());
 
         self
     }
 
-    pub fn zero_terms_query(&mut self, v: ZeroTermsQuery) -> &mut Self {
+    pub fn zero_terms_query(mut self, v: ZeroTermsQuery) -> Self {
         self.inner.opts.zero_terms_query = v;
 
         self
     }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.inner.boost = Boost(v);
+
+        self
+    }
 }
 
-impl<'a> Serialize for Match<'a> {
+impl Serialize for Match {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer
@@ -103,37 +113,64 @@ impl<'a> Serialize for Match<'a> {
     }
 }
 
-#[derive(Serialize)]
-struct Inner<'a> {
+impl<'de> Deserialize<'de> for Match {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let (field, inner) = deserialize_field_entry::<_, ShortOrFull<QueryValue, Inner>>(deserializer)?;
+
+        let inner = match inner {
+            ShortOrFull::Short(query) => Inner {
+                query,
+                opts: Default::default(),
+                boost: Default::default(),
+            },
+            ShortOrFull::Full(inner) => inner,
+        };
+
+        Ok(Self {
+            field,
+            inner,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Inner {
     query: QueryValue,
-    #[serde(flatten, skip_serializing_if = "MatchOptions::equals_to_default")]
-    opts: MatchOptions<'a>,
+    #[serde(flatten, default, skip_serializing_if = "MatchOptions::equals_to_default")]
+    opts: MatchOptions,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
 }
 
 #[skip_serializing_none]
-#[derive(PartialEq, Serialize)]
-struct MatchOptions<'a> {
-    analyzer: Option<Cow<'a, str>>,
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct MatchOptions {
+    pub(crate) analyzer: Option<Cow<'static, str>>,
     #[serde(skip_serializing_if = "AutoGenerateSynonymsPhraseQuery::equals_to_default")]
-    auto_generate_synonyms_phrase_query: AutoGenerateSynonymsPhraseQuery,
-    fuzziness: Option<Cow<'a, str>>,
+    pub(crate) auto_generate_synonyms_phrase_query: AutoGenerateSynonymsPhraseQuery,
+    pub(crate) fuzziness: Option<Cow<'static, str>>,
     #[serde(skip_serializing_if = "MaxExpansions::equals_to_default")]
-    max_expansions: MaxExpansions,
+    pub(crate) max_expansions: MaxExpansions,
     #[serde(skip_serializing_if = "PrefixLength::equals_to_default")]
-    prefix_length: PrefixLength,
+    pub(crate) prefix_length: PrefixLength,
     #[serde(skip_serializing_if = "FuzzyTranspositions::equals_to_default")]
-    fuzzy_transpositions: FuzzyTranspositions,
-    fuzzy_rewrite: Option<Cow<'a, str>>,
+    pub(crate) fuzzy_transpositions: FuzzyTranspositions,
+    pub(crate) fuzzy_rewrite: Option<Cow<'static, str>>,
     #[serde(skip_serializing_if = "Lenient::equals_to_default")]
-    lenient: Lenient,
+    pub(crate) lenient: Lenient,
     #[serde(skip_serializing_if = "Operator::equals_to_default")]
-    operator: Operator,
-    minimum_should_match: Option<Cow<'a, str>>,
+    pub(crate) operator: Operator,
+    pub(crate) minimum_should_match: Option<Cow<'static, str>>,
     #[serde(skip_serializing_if = "ZeroTermsQuery::equals_to_default")]
-    zero_terms_query: ZeroTermsQuery,
+    pub(crate) zero_terms_query: ZeroTermsQuery,
 }
 
-impl<'a> Default for MatchOptions<'a> {
+impl Default for MatchOptions {
     fn default() -> Self {
         Self {
             analyzer: None,
@@ -151,10 +188,10 @@ impl<'a> Default for MatchOptions<'a> {
     }
 }
 
-impl<'a> EqualsToDefault for MatchOptions<'a> {}
+impl EqualsToDefault for MatchOptions {}
 
-#[derive(PartialEq, Serialize)]
-struct AutoGenerateSynonymsPhraseQuery(bool);
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct AutoGenerateSynonymsPhraseQuery(pub(crate) bool);
 
 impl Default for AutoGenerateSynonymsPhraseQuery {
     fn default() -> Self {
@@ -164,8 +201,8 @@ impl Default for AutoGenerateSynonymsPhraseQuery {
 
 impl EqualsToDefault for AutoGenerateSynonymsPhraseQuery {}
 
-#[derive(PartialEq, Serialize)]
-struct MaxExpansions(i32);
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct MaxExpansions(pub(crate) i32);
 
 impl Default for MaxExpansions {
     fn default() -> Self {
@@ -175,8 +212,8 @@ impl Default for MaxExpansions {
 
 impl EqualsToDefault for MaxExpansions {}
 
-#[derive(PartialEq, Serialize)]
-struct PrefixLength(i32);
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct PrefixLength(pub(crate) i32);
 
 impl Default for PrefixLength {
     fn default() -> Self {
@@ -186,8 +223,8 @@ impl Default for PrefixLength {
 
 impl EqualsToDefault for PrefixLength {}
 
-#[derive(PartialEq, Serialize)]
-struct FuzzyTranspositions(bool);
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct FuzzyTranspositions(pub(crate) bool);
 
 impl Default for FuzzyTranspositions {
     fn default() -> Self {
@@ -197,8 +234,8 @@ impl Default for FuzzyTranspositions {
 
 impl EqualsToDefault for FuzzyTranspositions {}
 
-#[derive(PartialEq, Serialize)]
-struct Lenient(bool);
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Lenient(pub(crate) bool);
 
 impl Default for Lenient {
     fn default() -> Self {
@@ -209,7 +246,7 @@ impl Default for Lenient {
 impl EqualsToDefault for Lenient {}
 
 #[allow(dead_code)]
-#[derive(DisplayCase, PartialEq, SerializeDisplay)]
+#[derive(Clone, Debug, DisplayCase, FromStrCase, PartialEq, SerializeDisplay, DeserializeFromStr)]
 #[display_case(case = "uppercase")]
 pub enum Operator {
     And,
@@ -225,7 +262,7 @@ impl Default for Operator {
 impl EqualsToDefault for Operator {}
 
 #[allow(dead_code)]
-#[derive(DisplayCase, PartialEq, SerializeDisplay)]
+#[derive(Clone, Debug, DisplayCase, FromStrCase, PartialEq, SerializeDisplay, DeserializeFromStr)]
 #[display_case(case = "lowercase")]
 pub enum ZeroTermsQuery {
     All,
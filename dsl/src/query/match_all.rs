@@ -1,3 +1,3 @@
 use crate::shortcuts::Empty;
 
-pub type MatchAll<'a> = Empty<'a>;
+pub type MatchAll = Empty;
@@ -0,0 +1,172 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, ser::SerializeMap};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    de::{deserialize_field_entry, ShortOrFull},
+    field::Field,
+    types::EqualsToDefault,
+};
+
+use super::{
+    r#match::{
+        FuzzyTranspositions,
+        MaxExpansions,
+        Operator,
+        PrefixLength,
+    },
+    QueryValue,
+};
+
+/// Reference: [Match boolean prefix query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-match-bool-prefix-query.html)
+#[derive(Clone, Debug)]
+pub struct MatchBoolPrefix {
+    field: Field,
+    inner: Inner,
+}
+
+impl MatchBoolPrefix {
+    pub fn new(field: impl Into<Field>, value: QueryValue) -> Self {
+        Self {
+            field: field.into(),
+            inner: Inner {
+                query: value,
+                opts: Default::default(),
+                boost: Default::default(),
+            },
+        }
+    }
+
+    pub fn analyzer(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.inner.opts.analyzer = Some(v.into());
+
+        self
+    }
+
+    pub fn fuzziness(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.inner.opts.fuzziness = Some(v.into());
+
+        self
+    }
+
+    pub fn prefix_length(mut self, v: i32) -> Self {
+        self.inner.opts.prefix_length = PrefixLength(v);
+
+        self
+    }
+
+    pub fn max_expansions(mut self, v: i32) -> Self {
+        self.inner.opts.max_expansions = MaxExpansions(v);
+
+        self
+    }
+
+    pub fn fuzzy_transpositions(mut self, v: bool) -> Self {
+        self.inner.opts.fuzzy_transpositions = FuzzyTranspositions(v);
+
+        self
+    }
+
+    pub fn fuzzy_rewrite(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.inner.opts.fuzzy_rewrite = Some(v.into());
+
+        self
+    }
+
+    pub fn minimum_should_match(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.inner.opts.minimum_should_match = Some(v.into());
+
+        self
+    }
+
+    pub fn operator(mut self, v: Operator) -> Self {
+        self.inner.opts.operator = v;
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.inner.boost = Boost(v);
+
+        self
+    }
+}
+
+impl Serialize for MatchBoolPrefix {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(Some(1))?;
+        m.serialize_entry(&self.field, &self.inner)?;
+        m.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for MatchBoolPrefix {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let (field, inner) = deserialize_field_entry::<_, ShortOrFull<QueryValue, Inner>>(deserializer)?;
+
+        let inner = match inner {
+            ShortOrFull::Short(query) => Inner {
+                query,
+                opts: Default::default(),
+                boost: Default::default(),
+            },
+            ShortOrFull::Full(inner) => inner,
+        };
+
+        Ok(Self {
+            field,
+            inner,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Inner {
+    query: QueryValue,
+    #[serde(flatten, default, skip_serializing_if = "MatchBoolPrefixOptions::equals_to_default")]
+    opts: MatchBoolPrefixOptions,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct MatchBoolPrefixOptions {
+    analyzer: Option<Cow<'static, str>>,
+    fuzziness: Option<Cow<'static, str>>,
+    #[serde(skip_serializing_if = "PrefixLength::equals_to_default")]
+    prefix_length: PrefixLength,
+    #[serde(skip_serializing_if = "MaxExpansions::equals_to_default")]
+    max_expansions: MaxExpansions,
+    #[serde(skip_serializing_if = "FuzzyTranspositions::equals_to_default")]
+    fuzzy_transpositions: FuzzyTranspositions,
+    fuzzy_rewrite: Option<Cow<'static, str>>,
+    minimum_should_match: Option<Cow<'static, str>>,
+    #[serde(skip_serializing_if = "Operator::equals_to_default")]
+    operator: Operator,
+}
+
+impl Default for MatchBoolPrefixOptions {
+    fn default() -> Self {
+        Self {
+            analyzer: None,
+            fuzziness: None,
+            prefix_length: Default::default(),
+            max_expansions: Default::default(),
+            fuzzy_transpositions: Default::default(),
+            fuzzy_rewrite: None,
+            minimum_should_match: None,
+            operator: Default::default(),
+        }
+    }
+}
+
+impl EqualsToDefault for MatchBoolPrefixOptions {}
@@ -1,3 +1,3 @@
 use crate::shortcuts::Empty;
 
-pub type MatchNone<'a> = Empty<'a>;
+pub type MatchNone = Empty;
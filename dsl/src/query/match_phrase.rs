@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, ser::SerializeMap};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    de::{deserialize_field_entry, ShortOrFull},
+    field::Field,
+    types::EqualsToDefault,
+};
+
+use super::{
+    r#match::ZeroTermsQuery,
+    QueryValue,
+};
+
+/// Reference: [Match phrase query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-match-query-phrase.html)
+#[derive(Clone, Debug)]
+pub struct MatchPhrase {
+    field: Field,
+    inner: Inner,
+}
+
+impl MatchPhrase {
+    pub fn new(field: impl Into<Field>, value: QueryValue) -> Self {
+        Self {
+            field: field.into(),
+            inner: Inner {
+                query: value,
+                opts: Default::default(),
+                boost: Default::default(),
+            },
+        }
+    }
+
+    pub fn analyzer(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.inner.opts.analyzer = Some(v.into());
+
+        self
+    }
+
+    pub fn slop(mut self, v: i32) -> Self {
+        self.inner.opts.slop = Slop(v);
+
+        self
+    }
+
+    pub fn zero_terms_query(mut self, v: ZeroTermsQuery) -> Self {
+        self.inner.opts.zero_terms_query = v;
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.inner.boost = Boost(v);
+
+        self
+    }
+}
+
+impl Serialize for MatchPhrase {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(Some(1))?;
+        m.serialize_entry(&self.field, &self.inner)?;
+        m.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for MatchPhrase {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let (field, inner) = deserialize_field_entry::<_, ShortOrFull<QueryValue, Inner>>(deserializer)?;
+
+        let inner = match inner {
+            ShortOrFull::Short(query) => Inner {
+                query,
+                opts: Default::default(),
+                boost: Default::default(),
+            },
+            ShortOrFull::Full(inner) => inner,
+        };
+
+        Ok(Self {
+            field,
+            inner,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Inner {
+    query: QueryValue,
+    #[serde(flatten, default, skip_serializing_if = "MatchPhraseOptions::equals_to_default")]
+    opts: MatchPhraseOptions,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct MatchPhraseOptions {
+    analyzer: Option<Cow<'static, str>>,
+    #[serde(skip_serializing_if = "Slop::equals_to_default")]
+    slop: Slop,
+    #[serde(skip_serializing_if = "ZeroTermsQuery::equals_to_default")]
+    zero_terms_query: ZeroTermsQuery,
+}
+
+impl Default for MatchPhraseOptions {
+    fn default() -> Self {
+        Self {
+            analyzer: None,
+            slop: Default::default(),
+            zero_terms_query: Default::default(),
+        }
+    }
+}
+
+impl EqualsToDefault for MatchPhraseOptions {}
+
+/// Maximum number of positions allowed between matching tokens. Defaults to 0.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Slop(pub(crate) i32);
+
+impl Default for Slop {
+    fn default() -> Self {
+        Self(0)
+    }
+}
+
+impl EqualsToDefault for Slop {}
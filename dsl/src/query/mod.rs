@@ -1,71 +1,149 @@
 pub mod bool;
+pub mod boosting;
+pub mod constant_score;
+pub mod dis_max;
+pub mod distance_feature;
 pub mod exists;
+pub mod field_masking_span;
+pub mod function_score;
+pub mod fuzzy;
+pub mod geo_bounding_box;
+pub mod geo_distance;
+pub mod geo_polygon;
+pub mod geo_shape;
+pub mod has_child;
+pub mod has_parent;
+pub mod ids;
+pub mod inner_hits;
+pub mod intervals;
 pub mod r#match;
 pub mod match_all;
+pub mod match_bool_prefix;
 pub mod match_none;
+pub mod match_phrase;
+pub mod match_phrase_prefix;
+pub mod more_like_this;
+pub mod multi_match;
 pub mod nested;
+pub mod parent_id;
+pub mod percolate;
+pub mod pinned;
+pub mod prefix;
+pub mod query_string;
 pub mod range;
+pub mod rank_feature;
+pub mod regexp;
+pub mod script;
+pub mod script_score;
+pub mod simple_query_string;
+pub mod span;
+pub mod span_containing;
+pub mod span_first;
+pub mod span_multi;
+pub mod span_near;
+pub mod span_not;
+pub mod span_or;
+pub mod span_term;
+pub mod span_within;
 pub mod term;
+pub mod terms;
+pub mod terms_set;
 pub mod prelude;
 pub mod wildcard;
 
-use std::rc::Rc;
-use serde::{Serialize, Serializer};
+use std::fmt;
+use serde::{
+    de::{self, Visitor},
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
 use bool::Bool;
+use boosting::Boosting;
+use constant_score::ConstantScore;
+use dis_max::DisMax;
+use distance_feature::DistanceFeature;
 use exists::Exists;
+use field_masking_span::FieldMaskingSpan;
+use function_score::FunctionScore;
+use fuzzy::Fuzzy;
+use geo_bounding_box::GeoBoundingBox;
+use geo_distance::GeoDistance;
+use geo_polygon::GeoPolygon;
+use geo_shape::GeoShape;
+use has_child::HasChild;
+use has_parent::HasParent;
+use ids::Ids;
+use intervals::Intervals;
 use r#match::Match;
 use match_all::MatchAll;
+use match_bool_prefix::MatchBoolPrefix;
 use match_none::MatchNone;
+use match_phrase::MatchPhrase;
+use match_phrase_prefix::MatchPhrasePrefix;
+use more_like_this::MoreLikeThis;
+use multi_match::MultiMatch;
 use nested::Nested;
+use parent_id::ParentId;
+use percolate::Percolate;
+use pinned::Pinned;
+use prefix::Prefix;
+use query_string::QueryString;
+use rank_feature::RankFeature;
+use regexp::Regexp;
+use script::ScriptQuery;
+use script_score::ScriptScore;
+use span_containing::SpanContaining;
+use span_first::SpanFirst;
+use span_multi::SpanMulti;
+use span_near::SpanNear;
+use span_not::SpanNot;
+use span_or::SpanOr;
+use span_term::SpanTerm;
+use span_within::SpanWithin;
 use term::Term;
+use terms::Terms;
+use terms_set::TermsSet;
 use range::Range;
-use serde_with::skip_serializing_none;
+use simple_query_string::SimpleQueryString;
 use wildcard::Wildcard;
 use crate::types::number::Number;
 
-#[skip_serializing_none]
-#[derive(Clone, Serialize)]
-pub struct Query<'a> {
-    r#bool: Option<&'a Bool<'a>>,
-    nested: Option<Rc<Nested<'a>>>,
-    wildcard: Option<&'a Wildcard<'a>>,
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+pub enum QueryValue {
+    Text(String),
+    Number(Number),
+    Boolean(bool),
+    // Date,
 }
 
-impl<'a> Query<'a> {
-    pub fn new() -> Self {
-        Self {
-            r#bool: None,
-            nested: None,
-            wildcard: None,
-        }
-    }
-
-    pub fn bool(&mut self, q: &'a Bool) -> &mut Self {
-        self.r#bool = Some(q);
-
-        self
+impl From<String> for QueryValue {
+    fn from(v: String) -> Self {
+        Self::Text(v)
     }
+}
 
-    pub fn nested(&mut self, q: Nested<'a>) -> &mut Self {
-        self.nested = Some(Rc::new(q));
-
-        self
+impl From<&str> for QueryValue {
+    fn from(v: &str) -> Self {
+        Self::Text(v.to_owned())
     }
+}
 
-    pub fn wildcard(&mut self, q: &'a Wildcard<'a>) -> &mut Self {
-        self.wildcard = Some(q);
-
-        self
+impl From<bool> for QueryValue {
+    fn from(v: bool) -> Self {
+        Self::Boolean(v)
     }
 }
 
-#[allow(dead_code)]
-#[derive(Clone)]
-pub enum QueryValue {
-    Text(String),
-    Number(Number),
-    Boolean(bool),
-    // Date,
+impl<T> From<T> for QueryValue
+where
+    T: Into<Number>,
+{
+    fn from(v: T) -> Self {
+        Self::Number(v.into())
+    }
 }
 
 impl Serialize for QueryValue {
@@ -81,25 +159,117 @@ impl Serialize for QueryValue {
     }
 }
 
-macro_rules! declare_leaf_clause {
-    ($($clause_name:ident),*) => {
-        #[derive(Clone, Serialize)]
+impl<'de> Deserialize<'de> for QueryValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        struct QueryValueVisitor;
+
+        impl<'de> Visitor<'de> for QueryValueVisitor {
+            type Value = QueryValue;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a string, a number or a boolean")
+            }
+
+            fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+                Ok(QueryValue::Boolean(v))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(QueryValue::Number(Number::I64(v)))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(QueryValue::Number(Number::U64(v)))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(QueryValue::Number(Number::F64(v)))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(QueryValue::Text(v.to_owned()))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(QueryValue::Text(v))
+            }
+        }
+
+        deserializer.deserialize_any(QueryValueVisitor)
+    }
+}
+
+macro_rules! declare_query {
+    ($($clause_name:ident $(= $clause_type:ident)?),*) => {
+        /// A query clause, serialized as `{"<clause_name>": {...}}`
+        ///
+        /// Compound clauses (`Bool`, `Nested`) hold further `Query` values,
+        /// so a `Query` can be used at the top level of a `Search`, inside
+        /// any `Bool` occurrence, as a `Nested` query or as a `SortNested`
+        /// filter.
+        #[derive(Clone, Debug, Serialize, Deserialize)]
         #[serde(rename_all = "snake_case")]
-        pub enum LeafClause<'a> {
-            $($clause_name(&'a $clause_name<'a>),)*
+        pub enum Query {
+            $($clause_name(declare_query!(@type $clause_name $($clause_type)?)),)*
         }
     };
+    // A variant holds the type of the same name, unless another one is given,
+    // e.g. `Script = ScriptQuery` to avoid clashing with `script::Script`
+    (@type $clause_name:ident) => { $clause_name };
+    (@type $clause_name:ident $clause_type:ident) => { $clause_type };
 }
 
-declare_leaf_clause!(
+declare_query!(
+    Bool,
+    Boosting,
+    ConstantScore,
+    DisMax,
+    DistanceFeature,
     Exists,
+    FieldMaskingSpan,
+    FunctionScore,
+    Fuzzy,
+    GeoBoundingBox,
+    GeoDistance,
+    GeoPolygon,
+    GeoShape,
+    HasChild,
+    HasParent,
+    Ids,
+    Intervals,
     Match,
     MatchAll,
+    MatchBoolPrefix,
     MatchNone,
+    MatchPhrase,
+    MatchPhrasePrefix,
+    MoreLikeThis,
+    MultiMatch,
+    Nested,
+    ParentId,
+    Percolate,
+    Pinned,
+    Prefix,
+    QueryString,
+    Range,
+    RankFeature,
+    Regexp,
+    Script = ScriptQuery,
+    ScriptScore,
+    SimpleQueryString,
+    SpanContaining,
+    SpanFirst,
+    SpanMulti,
+    SpanNear,
+    SpanNot,
+    SpanOr,
+    SpanTerm,
+    SpanWithin,
     Term,
-    Range
+    Terms,
+    TermsSet,
+    Wildcard
 );
-
-
-
-
@@ -0,0 +1,265 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    field::Field,
+    types::EqualsToDefault,
+};
+
+/// Build a more_like_this Query, matching documents similar to the given texts and documents
+///
+/// Reference: [More like this query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-mlt-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::query::{
+///     more_like_this::{LikeDocument, MoreLikeThis},
+///     Query,
+/// };
+///
+/// #[derive(serde::Serialize)]
+/// struct Article {
+///     title: &'static str,
+/// }
+///
+/// let q = Query::MoreLikeThis(
+///     MoreLikeThis::new("Once upon a time")
+///         .field("title")
+///         .field("description")
+///         .like(LikeDocument::new("imdb", "1"))
+///         .like(LikeDocument::try_artificial(&Article { title: "The Godfather" }).unwrap().index("imdb"))
+///         .unlike(LikeDocument::new("imdb", "2"))
+///         .min_term_freq(1)
+///         .max_query_terms(12)
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MoreLikeThis {
+    fields: Option<Vec<Field>>,
+    #[serde(deserialize_with = "crate::de::one_or_many_required")]
+    like: Vec<Like>,
+    #[serde(default, deserialize_with = "crate::de::one_or_many")]
+    unlike: Option<Vec<Like>>,
+    max_query_terms: Option<i32>,
+    min_term_freq: Option<i32>,
+    min_doc_freq: Option<i32>,
+    max_doc_freq: Option<i32>,
+    min_word_length: Option<i32>,
+    max_word_length: Option<i32>,
+    stop_words: Option<Vec<Cow<'static, str>>>,
+    analyzer: Option<Cow<'static, str>>,
+    minimum_should_match: Option<Cow<'static, str>>,
+    fail_on_unsupported_field: Option<bool>,
+    boost_terms: Option<f32>,
+    include: Option<bool>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl MoreLikeThis {
+    /// `like` is required, more texts and documents are added with `like`
+    pub fn new(like: impl Into<Like>) -> Self {
+        Self {
+            fields: None,
+            like: vec![like.into()],
+            unlike: None,
+            max_query_terms: None,
+            min_term_freq: None,
+            min_doc_freq: None,
+            max_doc_freq: None,
+            min_word_length: None,
+            max_word_length: None,
+            stop_words: None,
+            analyzer: None,
+            minimum_should_match: None,
+            fail_on_unsupported_field: None,
+            boost_terms: None,
+            include: None,
+            boost: Default::default(),
+        }
+    }
+
+    pub fn field(mut self, field: impl Into<Field>) -> Self {
+        self.fields.get_or_insert_with(Vec::new).push(field.into());
+
+        self
+    }
+
+    pub fn like(mut self, v: impl Into<Like>) -> Self {
+        self.like.push(v.into());
+
+        self
+    }
+
+    pub fn unlike(mut self, v: impl Into<Like>) -> Self {
+        self.unlike.get_or_insert_with(Vec::new).push(v.into());
+
+        self
+    }
+
+    pub fn max_query_terms(mut self, v: i32) -> Self {
+        self.max_query_terms = Some(v);
+
+        self
+    }
+
+    pub fn min_term_freq(mut self, v: i32) -> Self {
+        self.min_term_freq = Some(v);
+
+        self
+    }
+
+    pub fn min_doc_freq(mut self, v: i32) -> Self {
+        self.min_doc_freq = Some(v);
+
+        self
+    }
+
+    pub fn max_doc_freq(mut self, v: i32) -> Self {
+        self.max_doc_freq = Some(v);
+
+        self
+    }
+
+    pub fn min_word_length(mut self, v: i32) -> Self {
+        self.min_word_length = Some(v);
+
+        self
+    }
+
+    pub fn max_word_length(mut self, v: i32) -> Self {
+        self.max_word_length = Some(v);
+
+        self
+    }
+
+    pub fn stop_words<T>(mut self, v: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        self.stop_words = Some(v.into_iter().map(Into::into).collect());
+
+        self
+    }
+
+    pub fn analyzer(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.analyzer = Some(v.into());
+
+        self
+    }
+
+    pub fn minimum_should_match(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.minimum_should_match = Some(v.into());
+
+        self
+    }
+
+    pub fn fail_on_unsupported_field(mut self, v: bool) -> Self {
+        self.fail_on_unsupported_field = Some(v);
+
+        self
+    }
+
+    pub fn boost_terms(mut self, v: f32) -> Self {
+        self.boost_terms = Some(v);
+
+        self
+    }
+
+    /// Whether the input documents should also be returned
+    pub fn include(mut self, v: bool) -> Self {
+        self.include = Some(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+/// An entry of `like` or `unlike`, a free text or a document
+#[allow(dead_code)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Like {
+    Text(Cow<'static, str>),
+    Document(LikeDocument),
+}
+
+impl From<&'static str> for Like {
+    fn from(v: &'static str) -> Self {
+        Self::Text(v.into())
+    }
+}
+
+impl From<String> for Like {
+    fn from(v: String) -> Self {
+        Self::Text(v.into())
+    }
+}
+
+impl From<LikeDocument> for Like {
+    fn from(v: LikeDocument) -> Self {
+        Self::Document(v)
+    }
+}
+
+/// A document indexed in `_index` with `_id`, or an artificial document
+/// which isn't indexed, given as `doc`
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LikeDocument {
+    _index: Option<Cow<'static, str>>,
+    _id: Option<Cow<'static, str>>,
+    doc: Option<serde_json::Value>,
+    routing: Option<Cow<'static, str>>,
+}
+
+impl LikeDocument {
+    pub fn new(index: impl Into<Cow<'static, str>>, id: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            _index: Some(index.into()),
+            _id: Some(id.into()),
+            doc: None,
+            routing: None,
+        }
+    }
+
+    pub fn artificial(doc: serde_json::Value) -> Self {
+        Self {
+            _index: None,
+            _id: None,
+            doc: Some(doc),
+            routing: None,
+        }
+    }
+
+    /// An artificial document from any serializable value, fails when it
+    /// can't be represented as JSON, e.g. a map with non-string keys
+    pub fn try_artificial<T>(doc: &T) -> serde_json::Result<Self>
+    where
+        T: Serialize,
+    {
+        Ok(Self::artificial(serde_json::to_value(doc)?))
+    }
+
+    /// The index of an artificial document, defaults to the queried index
+    pub fn index(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self._index = Some(v.into());
+
+        self
+    }
+
+    pub fn routing(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.routing = Some(v.into());
+
+        self
+    }
+}
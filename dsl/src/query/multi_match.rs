@@ -0,0 +1,200 @@
+use std::borrow::Cow;
+use macros::{DisplayCase, FromStrCase};
+use serde::{Deserialize, Serialize};
+use serde_with::{skip_serializing_none, DeserializeFromStr, SerializeDisplay};
+use crate::{
+    boost::Boost,
+    field::{BoostedField, Field},
+    types::EqualsToDefault,
+};
+
+use super::{
+    match_phrase::Slop,
+    r#match::{
+        AutoGenerateSynonymsPhraseQuery,
+        FuzzyTranspositions,
+        Lenient,
+        MatchOptions,
+        MaxExpansions,
+        Operator,
+        PrefixLength,
+        ZeroTermsQuery,
+    },
+    QueryValue,
+};
+
+/// Build a multi_match Query
+///
+/// Reference: [Multi-match query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-multi-match-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::query::{
+///     multi_match::{MultiMatch, MultiMatchType},
+///     Query,
+///     QueryValue,
+/// };
+///
+/// let q = Query::MultiMatch(
+///     MultiMatch::new(QueryValue::Text("brown fox".to_owned()))
+///         .field_boost("title", 3.0)
+///         .field("body")
+///         .r#type(MultiMatchType::MostFields)
+///         .tie_breaker(0.3)
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MultiMatch {
+    query: QueryValue,
+    fields: Option<Vec<BoostedField>>,
+    #[serde(default, skip_serializing_if = "MultiMatchType::equals_to_default")]
+    r#type: MultiMatchType,
+    tie_breaker: Option<f32>,
+    #[serde(default, skip_serializing_if = "Slop::equals_to_default")]
+    slop: Slop,
+    #[serde(flatten, default, skip_serializing_if = "MatchOptions::equals_to_default")]
+    opts: MatchOptions,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl MultiMatch {
+    pub fn new(value: QueryValue) -> Self {
+        Self {
+            query: value,
+            fields: None,
+            r#type: Default::default(),
+            tie_breaker: None,
+            slop: Default::default(),
+            opts: Default::default(),
+            boost: Default::default(),
+        }
+    }
+
+    pub fn field(self, v: impl Into<Field>) -> Self {
+        self.push_field(BoostedField::new(v, None))
+    }
+
+    /// Add a field with a per-field boost, serialized as `field^boost`
+    pub fn field_boost(self, v: impl Into<Field>, boost: f32) -> Self {
+        self.push_field(BoostedField::new(v, Some(boost)))
+    }
+
+    fn push_field(mut self, field: BoostedField) -> Self {
+        self.fields.get_or_insert_with(Vec::new).push(field);
+
+        self
+    }
+
+    pub fn r#type(mut self, v: MultiMatchType) -> Self {
+        self.r#type = v;
+
+        self
+    }
+
+    pub fn tie_breaker(mut self, v: f32) -> Self {
+        self.tie_breaker = Some(v);
+
+        self
+    }
+
+    pub fn slop(mut self, v: i32) -> Self {
+        self.slop = Slop(v);
+
+        self
+    }
+
+    pub fn analyzer(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.opts.analyzer = Some(v.into());
+
+        self
+    }
+
+    pub fn auto_generate_synonyms_phrase_query(mut self, v: bool) -> Self {
+        self.opts.auto_generate_synonyms_phrase_query = AutoGenerateSynonymsPhraseQuery(v);
+
+        self
+    }
+
+    pub fn fuzziness(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.opts.fuzziness = Some(v.into());
+
+        self
+    }
+
+    pub fn max_expansions(mut self, v: i32) -> Self {
+        self.opts.max_expansions = MaxExpansions(v);
+
+        self
+    }
+
+    pub fn prefix_length(mut self, v: i32) -> Self {
+        self.opts.prefix_length = PrefixLength(v);
+
+        self
+    }
+
+    pub fn fuzzy_transpositions(mut self, v: bool) -> Self {
+        self.opts.fuzzy_transpositions = FuzzyTranspositions(v);
+
+        self
+    }
+
+    pub fn fuzzy_rewrite(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.opts.fuzzy_rewrite = Some(v.into());
+
+        self
+    }
+
+    pub fn lenient(mut self, v: bool) -> Self {
+        self.opts.lenient = Lenient(v);
+
+        self
+    }
+
+    pub fn operator(mut self, v: Operator) -> Self {
+        self.opts.operator = v;
+
+        self
+    }
+
+    pub fn minimum_should_match(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.opts.minimum_should_match = Some(v.into());
+
+        self
+    }
+
+    pub fn zero_terms_query(mut self, v: ZeroTermsQuery) -> Self {
+        self.opts.zero_terms_query = v;
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Debug, DisplayCase, FromStrCase, PartialEq, SerializeDisplay, DeserializeFromStr)]
+#[display_case(case = "snakecase")]
+pub enum MultiMatchType {
+    BestFields,
+    MostFields,
+    CrossFields,
+    Phrase,
+    PhrasePrefix,
+    BoolPrefix,
+}
+
+impl Default for MultiMatchType {
+    fn default() -> Self {
+        Self::BestFields
+    }
+}
+
+impl EqualsToDefault for MultiMatchType {}
@@ -1,10 +1,13 @@
 use std::borrow::Cow;
-use macros::DisplayCase;
-use serde::Serialize;
-use serde_with::SerializeDisplay;
+use macros::{DisplayCase, FromStrCase};
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
 
-use crate::types::EqualsToDefault;
-use super::Query;
+use crate::{
+    ignore_unmapped::IgnoreUnmapped,
+    types::EqualsToDefault,
+};
+use super::{inner_hits::InnerHits, Query};
 
 /// Build a nested Query
 /// 
@@ -21,59 +24,66 @@ use super::Query;
 ///         QueryValue,
 ///     },
 /// };
-/// let q = Query::new()
-///     .nested(
-///         Nested::new(
-///             "event",
-///             Query::new()
-///                 .bool(
-///                     Bool::new()
-///                         .must(
-///                             clause!(
-///                                 Match,
-///                                 "event.action",
-///                                 QueryValue::Text("logged-in".to_owned())
-///                             )
-///                         )
+/// let q = Query::Nested(
+///     Nested::new(
+///         "event",
+///         Query::Bool(
+///             Bool::new()
+///                 .must(
+///                     clause!(
+///                         Match,
+///                         "event.action",
+///                         QueryValue::Text("logged-in".to_owned())
+///                     )
 ///                 )
-///                 .clone()
 ///         )
-///     );
+///     )
+/// );
 /// ```
-#[derive(Clone, Serialize)]
-pub struct Nested<'a> {
-    path: Cow<'a, str>,
-    query: Query<'a>,
-    #[serde(skip_serializing_if = "ScoreMode::equals_to_default")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Nested {
+    path: Cow<'static, str>,
+    query: Box<Query>,
+    #[serde(default, skip_serializing_if = "ScoreMode::equals_to_default")]
     score_mode: ScoreMode,
-    #[serde(skip_serializing_if = "IgnoreUnmapped::equals_to_default")]
+    #[serde(default, skip_serializing_if = "IgnoreUnmapped::equals_to_default")]
     ignore_unmapped: IgnoreUnmapped,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    inner_hits: Option<InnerHits>,
 }
 
-impl<'a> Nested<'a> {
-    pub fn new(path: &'a str, query: Query<'a>) -> Self {
+impl Nested {
+    pub fn new(path: impl Into<Cow<'static, str>>, query: Query) -> Self {
         Self {
             path: path.into(),
-            query,
+            query: Box::new(query),
             score_mode: Default::default(),
             ignore_unmapped: Default::default(),
+            inner_hits: None,
         }
     }
 
-    pub fn score_mode(&mut self, v: ScoreMode) -> &mut Self {
+    pub fn score_mode(mut self, v: ScoreMode) -> Self {
         self.score_mode = v;
         
         self
     }
 
-    pub fn ignore_unmapped(&mut self, v: bool) -> &mut Self {
+    pub fn ignore_unmapped(mut self, v: bool) -> Self {
         self.ignore_unmapped = IgnoreUnmapped(v);
 
         self
     }
+
+    pub fn inner_hits(mut self, v: InnerHits) -> Self {
+        self.inner_hits = Some(v);
+
+        self
+    }
 }
 
-#[derive(Clone, PartialEq, DisplayCase, SerializeDisplay)]
+#[derive(Clone, Debug, PartialEq, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
 #[display_case(case = "lowercase")]
 pub enum ScoreMode {
     Avg,
@@ -90,14 +100,3 @@ impl Default for ScoreMode {
 }
 
 impl EqualsToDefault for ScoreMode {}
-
-#[derive(Clone, PartialEq, Serialize)]
-struct IgnoreUnmapped(bool);
-
-impl Default for IgnoreUnmapped {
-    fn default() -> Self {
-        Self(false)
-    }
-}
-
-impl EqualsToDefault for IgnoreUnmapped {}
@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use crate::{
+    ignore_unmapped::IgnoreUnmapped,
+    types::EqualsToDefault,
+};
+
+/// Build a parent_id Query, matching the child documents joined to a specific parent document
+///
+/// Elasticsearch doesn't support `inner_hits` on this query, use `HasParent`
+/// with an `ids` query to retrieve the parent along with its children.
+///
+/// Reference: [Parent ID query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-parent-id-query.html)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ParentId {
+    r#type: Cow<'static, str>,
+    id: Cow<'static, str>,
+    #[serde(default, skip_serializing_if = "IgnoreUnmapped::equals_to_default")]
+    ignore_unmapped: IgnoreUnmapped,
+}
+
+impl ParentId {
+    pub fn new(r#type: impl Into<Cow<'static, str>>, id: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            r#type: r#type.into(),
+            id: id.into(),
+            ignore_unmapped: Default::default(),
+        }
+    }
+
+    pub fn ignore_unmapped(mut self, v: bool) -> Self {
+        self.ignore_unmapped = IgnoreUnmapped(v);
+
+        self
+    }
+}
@@ -0,0 +1,205 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize, Serializer, ser::SerializeMap};
+use serde_with::skip_serializing_none;
+use crate::field::Field;
+
+use super::Query;
+
+/// Build a percolate Query, matching the queries stored in a percolator field
+/// against one or more documents
+///
+/// Reference: [Percolate query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-percolate-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::query::{percolate::Percolate, Query};
+///
+/// let q = Query::Percolate(
+///     Percolate::document("query", serde_json::json!({"message": "A new bonsai tree in the office"}))
+/// );
+///
+/// let q = Query::Percolate(Percolate::indexed("query", "my-index-000001", "2").name("stored"));
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Percolate {
+    field: Field,
+    #[serde(flatten)]
+    source: PercolateSource,
+    name: Option<Cow<'static, str>>,
+}
+
+impl Percolate {
+    pub fn document(field: impl Into<Field>, document: serde_json::Value) -> Self {
+        Self::with(field, PercolateSource::Document { document })
+    }
+
+    /// A document from any serializable value, fails when it can't be
+    /// represented as JSON, e.g. a map with non-string keys
+    pub fn try_document<T>(field: impl Into<Field>, document: &T) -> serde_json::Result<Self>
+    where
+        T: Serialize,
+    {
+        Ok(Self::document(field, serde_json::to_value(document)?))
+    }
+
+    pub fn documents(field: impl Into<Field>, documents: impl IntoIterator<Item = serde_json::Value>) -> Self {
+        Self::with(
+            field,
+            PercolateSource::Documents {
+                documents: documents.into_iter().collect(),
+            }
+        )
+    }
+
+    /// Percolate an already indexed document
+    pub fn indexed(
+        field: impl Into<Field>,
+        index: impl Into<Cow<'static, str>>,
+        id: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self::with(
+            field,
+            PercolateSource::Indexed {
+                index: index.into(),
+                id: id.into(),
+                routing: None,
+                preference: None,
+                version: None,
+            }
+        )
+    }
+
+    fn with(field: impl Into<Field>, source: PercolateSource) -> Self {
+        Self {
+            field: field.into(),
+            source,
+            name: None,
+        }
+    }
+
+    /// The suffix of the `_percolator_document_slot` field in the response,
+    /// needed when a search holds more than one percolate query
+    pub fn name(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(v.into());
+
+        self
+    }
+
+    /// Only applies to an indexed document
+    pub fn routing(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        if let PercolateSource::Indexed { routing, .. } = &mut self.source {
+            *routing = Some(v.into());
+        }
+
+        self
+    }
+
+    /// Only applies to an indexed document
+    pub fn preference(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        if let PercolateSource::Indexed { preference, .. } = &mut self.source {
+            *preference = Some(v.into());
+        }
+
+        self
+    }
+
+    /// Only applies to an indexed document
+    pub fn version(mut self, v: i64) -> Self {
+        if let PercolateSource::Indexed { version, .. } = &mut self.source {
+            *version = Some(v);
+        }
+
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum PercolateSource {
+    Document {
+        document: serde_json::Value,
+    },
+    Documents {
+        documents: Vec<serde_json::Value>,
+    },
+    Indexed {
+        index: Cow<'static, str>,
+        id: Cow<'static, str>,
+        routing: Option<Cow<'static, str>>,
+        preference: Option<Cow<'static, str>>,
+        version: Option<i64>,
+    },
+}
+
+/// A document to index into a percolator index, registering `query` in the
+/// percolator field, along with any other fields of the document
+///
+/// Reference: [Percolator field type](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/percolator.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     clause,
+///     query::{percolate::PercolatorDocument, Query, QueryValue},
+/// };
+///
+/// let doc = PercolatorDocument::new("query", clause!(Match, "message", QueryValue::from("bonsai tree")))
+///     .field("severity", serde_json::json!("warning"));
+///
+/// assert_eq!(
+///     serde_json::to_string(&doc).unwrap(),
+///     r#"{"query":{"match":{"message":{"query":"bonsai tree"}}},"severity":"warning"}"#
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct PercolatorDocument {
+    field: Field,
+    query: Query,
+    fields: Vec<(Cow<'static, str>, serde_json::Value)>,
+}
+
+impl PercolatorDocument {
+    pub fn new(field: impl Into<Field>, query: Query) -> Self {
+        Self {
+            field: field.into(),
+            query,
+            fields: vec![],
+        }
+    }
+
+    /// Adds a field of the document, replacing the one of the same name if any
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is the percolator field, which holds the `query`
+    pub fn field(mut self, name: impl Into<Cow<'static, str>>, value: serde_json::Value) -> Self {
+        let name = name.into();
+
+        if name == self.field.as_ref() {
+            panic!("the field `{}` holds the query of the percolator document", name);
+        }
+
+        match self.fields.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, v)) => *v = value,
+            None => self.fields.push((name, value)),
+        }
+
+        self
+    }
+}
+
+impl Serialize for PercolatorDocument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(Some(1 + self.fields.len()))?;
+        m.serialize_entry(&self.field, &self.query)?;
+        for (name, value) in &self.fields {
+            m.serialize_entry(name, value)?;
+        }
+        m.end()
+    }
+}
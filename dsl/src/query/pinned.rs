@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+
+use super::Query;
+
+/// Build a pinned Query, promoting the given documents above the results of the `organic` query
+///
+/// Reference: [Pinned query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-pinned-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     clause,
+///     query::{pinned::Pinned, Query, QueryValue},
+/// };
+///
+/// let q = Query::Pinned(Pinned::ids(["1", "4", "100"], clause!(Match, "description", QueryValue::from("iphone"))));
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Pinned {
+    #[serde(flatten)]
+    pinned: PinnedDocuments,
+    organic: Box<Query>,
+}
+
+impl Pinned {
+    pub fn ids<T>(ids: impl IntoIterator<Item = T>, organic: Query) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        Self {
+            pinned: PinnedDocuments::Ids(ids.into_iter().map(Into::into).collect()),
+            organic: Box::new(organic),
+        }
+    }
+
+    /// Documents which may live in different indices
+    pub fn docs(docs: impl IntoIterator<Item = PinnedDoc>, organic: Query) -> Self {
+        Self {
+            pinned: PinnedDocuments::Docs(docs.into_iter().collect()),
+            organic: Box::new(organic),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum PinnedDocuments {
+    Ids(Vec<Cow<'static, str>>),
+    Docs(Vec<PinnedDoc>),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PinnedDoc {
+    _index: Cow<'static, str>,
+    _id: Cow<'static, str>,
+}
+
+impl PinnedDoc {
+    pub fn new(index: impl Into<Cow<'static, str>>, id: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            _index: index.into(),
+            _id: id.into(),
+        }
+    }
+}
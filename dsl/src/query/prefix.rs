@@ -0,0 +1,102 @@
+use std::borrow::Cow;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    case_insensitive::CaseInsensitive,
+    de::{deserialize_field_entry, ShortOrFull},
+    field::Field,
+    types::EqualsToDefault,
+};
+
+/// Reference: [Prefix query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-prefix-query.html)
+#[derive(Clone, Debug)]
+pub struct Prefix {
+    field: Field,
+    opts: PrefixOptions,
+}
+
+impl Prefix {
+    pub fn new(field: impl Into<Field>, value: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            field: field.into(),
+            opts: PrefixOptions {
+                value: value.into(),
+                rewrite: None,
+                case_insensitive: Default::default(),
+                boost: Default::default(),
+            }
+        }
+    }
+
+    pub fn rewrite(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.opts.rewrite = Some(v.into());
+
+        self
+    }
+
+    pub fn case_insensitive(mut self, v: bool) -> Self {
+        self.opts.case_insensitive = CaseInsensitive(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.opts.boost = Boost(v);
+
+        self
+    }
+}
+
+impl Serialize for Prefix {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(Some(1))?;
+        m.serialize_entry(&self.field, &self.opts)?;
+        m.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Prefix {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let (field, opts) = deserialize_field_entry::<_, ShortOrFull<Cow<'static, str>, PrefixOptions>>(deserializer)?;
+
+        let opts = match opts {
+            ShortOrFull::Short(value) => PrefixOptions {
+                value,
+                rewrite: None,
+                case_insensitive: Default::default(),
+                boost: Default::default(),
+            },
+            ShortOrFull::Full(opts) => opts,
+        };
+
+        Ok(Self {
+            field,
+            opts,
+        })
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PrefixOptions {
+    value: Cow<'static, str>,
+    rewrite: Option<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "CaseInsensitive::equals_to_default")]
+    case_insensitive: CaseInsensitive,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
@@ -1,7 +1,51 @@
+pub use super::bool::Bool;
+pub use super::boosting::Boosting;
+pub use super::constant_score::ConstantScore;
+pub use super::dis_max::DisMax;
+pub use super::distance_feature::DistanceFeature;
+pub use super::exists::Exists;
+pub use super::field_masking_span::FieldMaskingSpan;
+pub use super::function_score::FunctionScore;
+pub use super::fuzzy::Fuzzy;
+pub use super::geo_bounding_box::GeoBoundingBox;
+pub use super::geo_distance::GeoDistance;
+pub use super::geo_polygon::GeoPolygon;
+pub use super::geo_shape::GeoShape;
+pub use super::has_child::HasChild;
+pub use super::has_parent::HasParent;
+pub use super::ids::Ids;
+pub use super::intervals::Intervals;
 pub use super::r#match::Match;
 pub use super::match_all::MatchAll;
+pub use super::match_bool_prefix::MatchBoolPrefix;
 pub use super::match_none::MatchNone;
-pub use super::exists::Exists;
+pub use super::match_phrase::MatchPhrase;
+pub use super::match_phrase_prefix::MatchPhrasePrefix;
+pub use super::more_like_this::MoreLikeThis;
+pub use super::multi_match::MultiMatch;
+pub use super::nested::Nested;
+pub use super::parent_id::ParentId;
+pub use super::percolate::Percolate;
+pub use super::pinned::Pinned;
+pub use super::prefix::Prefix;
+pub use super::query_string::QueryString;
 pub use super::range::Range;
+pub use super::rank_feature::RankFeature;
+pub use super::regexp::Regexp;
+pub use super::script::ScriptQuery;
+pub use super::script_score::ScriptScore;
+pub use super::simple_query_string::SimpleQueryString;
+pub use super::span::SpanQuery;
+pub use super::span_containing::SpanContaining;
+pub use super::span_first::SpanFirst;
+pub use super::span_multi::SpanMulti;
+pub use super::span_near::SpanNear;
+pub use super::span_not::SpanNot;
+pub use super::span_or::SpanOr;
+pub use super::span_term::SpanTerm;
+pub use super::span_within::SpanWithin;
 pub use super::term::Term;
-pub use super::LeafClause;
+pub use super::terms::Terms;
+pub use super::terms_set::TermsSet;
+pub use super::wildcard::Wildcard;
+pub use super::Query;
@@ -0,0 +1,175 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    field::{BoostedField, Field},
+    types::EqualsToDefault,
+};
+
+use super::r#match::{Lenient, Operator};
+
+/// Build a query_string Query, which parses the Lucene query syntax
+///
+/// Reference: [Query string query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-query-string-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::query::{
+///     query_string::QueryString,
+///     r#match::Operator,
+///     Query,
+/// };
+///
+/// let q = Query::QueryString(
+///     QueryString::new("(new york city) OR (big apple)")
+///         .default_field("content")
+///         .default_operator(Operator::And)
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QueryString {
+    query: Cow<'static, str>,
+    default_field: Option<Field>,
+    fields: Option<Vec<BoostedField>>,
+    #[serde(default, skip_serializing_if = "Operator::equals_to_default")]
+    default_operator: Operator,
+    analyzer: Option<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "AnalyzeWildcard::equals_to_default")]
+    analyze_wildcard: AnalyzeWildcard,
+    #[serde(default, skip_serializing_if = "AllowLeadingWildcard::equals_to_default")]
+    allow_leading_wildcard: AllowLeadingWildcard,
+    fuzziness: Option<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "Lenient::equals_to_default")]
+    lenient: Lenient,
+    minimum_should_match: Option<Cow<'static, str>>,
+    quote_field_suffix: Option<Cow<'static, str>>,
+    time_zone: Option<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl QueryString {
+    pub fn new(query: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            query: query.into(),
+            default_field: None,
+            fields: None,
+            default_operator: Default::default(),
+            analyzer: None,
+            analyze_wildcard: Default::default(),
+            allow_leading_wildcard: Default::default(),
+            fuzziness: None,
+            lenient: Default::default(),
+            minimum_should_match: None,
+            quote_field_suffix: None,
+            time_zone: None,
+            boost: Default::default(),
+        }
+    }
+
+    pub fn default_field(mut self, v: impl Into<Field>) -> Self {
+        self.default_field = Some(v.into());
+
+        self
+    }
+
+    pub fn field(self, v: impl Into<Field>) -> Self {
+        self.push_field(BoostedField::new(v, None))
+    }
+
+    /// Add a field with a per-field boost, serialized as `field^boost`
+    pub fn field_boost(self, v: impl Into<Field>, boost: f32) -> Self {
+        self.push_field(BoostedField::new(v, Some(boost)))
+    }
+
+    fn push_field(mut self, field: BoostedField) -> Self {
+        self.fields.get_or_insert_with(Vec::new).push(field);
+
+        self
+    }
+
+    pub fn default_operator(mut self, v: Operator) -> Self {
+        self.default_operator = v;
+
+        self
+    }
+
+    pub fn analyzer(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.analyzer = Some(v.into());
+
+        self
+    }
+
+    pub fn analyze_wildcard(mut self, v: bool) -> Self {
+        self.analyze_wildcard = AnalyzeWildcard(v);
+
+        self
+    }
+
+    pub fn allow_leading_wildcard(mut self, v: bool) -> Self {
+        self.allow_leading_wildcard = AllowLeadingWildcard(v);
+
+        self
+    }
+
+    pub fn fuzziness(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.fuzziness = Some(v.into());
+
+        self
+    }
+
+    pub fn lenient(mut self, v: bool) -> Self {
+        self.lenient = Lenient(v);
+
+        self
+    }
+
+    pub fn minimum_should_match(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.minimum_should_match = Some(v.into());
+
+        self
+    }
+
+    pub fn quote_field_suffix(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.quote_field_suffix = Some(v.into());
+
+        self
+    }
+
+    pub fn time_zone(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.time_zone = Some(v.into());
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct AnalyzeWildcard(pub(crate) bool);
+
+impl Default for AnalyzeWildcard {
+    fn default() -> Self {
+        Self(false)
+    }
+}
+
+impl EqualsToDefault for AnalyzeWildcard {}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct AllowLeadingWildcard(bool);
+
+impl Default for AllowLeadingWildcard {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl EqualsToDefault for AllowLeadingWildcard {}
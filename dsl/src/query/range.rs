@@ -1,9 +1,18 @@
 use std::borrow::Cow;
-use serde::{Serialize, Serializer, ser::SerializeMap};
-use serde_with::{skip_serializing_none, SerializeDisplay};
-use macros::DisplayCase;
+use std::fmt;
+use serde::{
+    de::{self, Visitor},
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
+use serde_with::{skip_serializing_none, DeserializeFromStr, SerializeDisplay};
+use macros::{DisplayCase, FromStrCase};
 use crate::{
     boost::Boost,
+    de::deserialize_field_entry,
     field::Field,
     types::{
         number::Number,
@@ -11,13 +20,13 @@ use crate::{
     },
 };
 
-#[derive(Clone)]
-pub struct Range<'a> {
-    field: Field<'a>,
-    opts: RangeOptions<'a>,
+#[derive(Clone, Debug)]
+pub struct Range {
+    field: Field,
+    opts: RangeOptions,
 }
 
-impl<'a> Serialize for Range<'a> {
+impl Serialize for Range {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -28,57 +37,71 @@ impl<'a> Serialize for Range<'a> {
     }
 }
 
-impl<'a> Range<'a> {
-    pub fn new(field: Field<'a>) -> Self {
-        Self {
+impl<'de> Deserialize<'de> for Range {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (field, opts) = deserialize_field_entry(deserializer)?;
+
+        Ok(Self {
             field,
+            opts,
+        })
+    }
+}
+
+impl Range {
+    pub fn new(field: impl Into<Field>) -> Self {
+        Self {
+            field: field.into(),
             opts: Default::default(),
         }
     }
 
-    pub fn gt(&mut self, v: RangeValue) -> &mut Self {
+    pub fn gt(mut self, v: RangeValue) -> Self {
         self.opts.gt = Some(v);
 
         self
     }
 
-    pub fn gte(&mut self, v: RangeValue) -> &mut Self {
+    pub fn gte(mut self, v: RangeValue) -> Self {
         self.opts.gte = Some(v);
 
         self
     }
 
-    pub fn lt(&mut self, v: RangeValue) -> &mut Self {
+    pub fn lt(mut self, v: RangeValue) -> Self {
         self.opts.lt = Some(v);
 
         self
     }
 
-    pub fn lte(&mut self, v: RangeValue) -> &mut Self {
+    pub fn lte(mut self, v: RangeValue) -> Self {
         self.opts.lte = Some(v);
 
         self
     }
 
-    pub fn format(&mut self, v: &'a str) -> &mut Self {
+    pub fn format(mut self, v: impl Into<Cow<'static, str>>) -> Self {
         self.opts.format = Some(v.into());
 
         self
     }
 
-    pub fn relation(&mut self, v: Relation) -> &mut Self {
+    pub fn relation(mut self, v: Relation) -> Self {
         self.opts.relation = v;
 
         self
     }
 
-    pub fn time_zone(&mut self, v: &'a str) -> &mut Self {
+    pub fn time_zone(mut self, v: impl Into<Cow<'static, str>>) -> Self {
         self.opts.time_zone = Some(v.into());
 
         self
     }
 
-    pub fn boost(&mut self, v: f32) -> &mut Self {
+    pub fn boost(mut self, v: f32) -> Self {
         self.opts.boost = Boost(v);
 
         self
@@ -86,21 +109,22 @@ impl<'a> Range<'a> {
 }
 
 #[skip_serializing_none]
-#[derive(Clone, Serialize)]
-struct RangeOptions<'a> {
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RangeOptions {
     gt: Option<RangeValue>,
     gte: Option<RangeValue>,
     lt: Option<RangeValue>,
     lte: Option<RangeValue>,
-    format: Option<Cow<'a, str>>,
+    format: Option<Cow<'static, str>>,
     #[serde(skip_serializing_if = "Relation::equals_to_default")]
     relation: Relation,
-    time_zone: Option<Cow<'a, str>>,
+    time_zone: Option<Cow<'static, str>>,
     #[serde(skip_serializing_if = "Boost::equals_to_default")]
     boost: Boost,
 }
 
-impl<'a> Default for RangeOptions<'a> {
+impl Default for RangeOptions {
     fn default() -> Self {
         Self {
             gt: None,
@@ -116,7 +140,7 @@ impl<'a> Default for RangeOptions<'a> {
 }
 
 #[allow(dead_code)]
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub enum RangeValue {
     Number(Number),
     Date(String),
@@ -134,8 +158,47 @@ impl Serialize for RangeValue {
     }
 }
 
+impl<'de> Deserialize<'de> for RangeValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        struct RangeValueVisitor;
+
+        impl<'de> Visitor<'de> for RangeValueVisitor {
+            type Value = RangeValue;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a number or a date string")
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(RangeValue::Number(Number::I64(v)))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(RangeValue::Number(Number::U64(v)))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(RangeValue::Number(Number::F64(v)))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(RangeValue::Date(v.to_owned()))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(RangeValue::Date(v))
+            }
+        }
+
+        deserializer.deserialize_any(RangeValueVisitor)
+    }
+}
+
 #[allow(dead_code)]
-#[derive(Clone, Debug, DisplayCase, SerializeDisplay, PartialEq)]
+#[derive(Clone, Debug, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr, PartialEq)]
 #[display_case(case = "uppercase")]
 pub enum Relation {
     Intersects,
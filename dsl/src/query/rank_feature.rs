@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    field::Field,
+    types::EqualsToDefault,
+};
+
+/// Build a rank_feature Query, boosting documents on the value of a
+/// `rank_feature` or `rank_features` field
+///
+/// Reference: [Rank feature query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-rank-feature-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::query::{
+///     rank_feature::{RankFeature, RankFeatureFunction},
+///     Query,
+/// };
+///
+/// let q = Query::RankFeature(
+///     RankFeature::new("pagerank").function(RankFeatureFunction::Log { scaling_factor: 4.0 })
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RankFeature {
+    field: Field,
+    #[serde(flatten)]
+    function: Option<RankFeatureFunction>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl RankFeature {
+    pub fn new(field: impl Into<Field>) -> Self {
+        Self {
+            field: field.into(),
+            function: None,
+            boost: Default::default(),
+        }
+    }
+
+    /// Defaults to `saturation` with a computed pivot
+    pub fn function(mut self, v: RankFeatureFunction) -> Self {
+        self.function = Some(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+/// The function computing the score from the rank feature value `S`
+///
+/// Reference: [Mathematical functions](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-rank-feature-query.html#rank-feature-query-saturation)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RankFeatureFunction {
+    /// `S / (S + pivot)`, the pivot defaults to an approximate geometric mean of the values
+    Saturation {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pivot: Option<f32>,
+    },
+    /// `log(scaling_factor + S)`
+    Log {
+        scaling_factor: f32,
+    },
+    /// `S^exp / (S^exp + pivot^exp)`
+    Sigmoid {
+        pivot: f32,
+        exponent: f32,
+    },
+    /// `S`
+    Linear {},
+}
@@ -0,0 +1,165 @@
+use std::borrow::Cow;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    case_insensitive::CaseInsensitive,
+    de::{deserialize_field_entry, ShortOrFull},
+    field::Field,
+    types::{
+        flags::declare_flags,
+        EqualsToDefault,
+    },
+};
+
+/// Build a regexp Query
+///
+/// Reference: [Regexp query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-regexp-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::query::{
+///     regexp::{Regexp, RegexpFlags},
+///     Query,
+/// };
+///
+/// let q = Query::Regexp(
+///     Regexp::new("user.id", "k.*y")
+///         .flags(RegexpFlags::COMPLEMENT | RegexpFlags::INTERVAL)
+///         .max_determinized_states(20000)
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Regexp {
+    field: Field,
+    opts: RegexpOptions,
+}
+
+impl Regexp {
+    pub fn new(field: impl Into<Field>, value: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            field: field.into(),
+            opts: RegexpOptions::new(value.into()),
+        }
+    }
+
+    pub fn flags(mut self, v: RegexpFlags) -> Self {
+        self.opts.flags = v;
+
+        self
+    }
+
+    pub fn max_determinized_states(mut self, v: i32) -> Self {
+        self.opts.max_determinized_states = MaxDeterminizedStates(v);
+
+        self
+    }
+
+    pub fn rewrite(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.opts.rewrite = Some(v.into());
+
+        self
+    }
+
+    pub fn case_insensitive(mut self, v: bool) -> Self {
+        self.opts.case_insensitive = CaseInsensitive(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.opts.boost = Boost(v);
+
+        self
+    }
+}
+
+impl Serialize for Regexp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(Some(1))?;
+        m.serialize_entry(&self.field, &self.opts)?;
+        m.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Regexp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let (field, opts) = deserialize_field_entry::<_, ShortOrFull<Cow<'static, str>, RegexpOptions>>(deserializer)?;
+
+        let opts = match opts {
+            ShortOrFull::Short(value) => RegexpOptions::new(value),
+            ShortOrFull::Full(opts) => opts,
+        };
+
+        Ok(Self {
+            field,
+            opts,
+        })
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RegexpOptions {
+    value: Cow<'static, str>,
+    #[serde(default, skip_serializing_if = "RegexpFlags::equals_to_default")]
+    flags: RegexpFlags,
+    #[serde(default, skip_serializing_if = "MaxDeterminizedStates::equals_to_default")]
+    max_determinized_states: MaxDeterminizedStates,
+    rewrite: Option<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "CaseInsensitive::equals_to_default")]
+    case_insensitive: CaseInsensitive,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl RegexpOptions {
+    fn new(value: Cow<'static, str>) -> Self {
+        Self {
+            value,
+            flags: Default::default(),
+            max_determinized_states: Default::default(),
+            rewrite: None,
+            case_insensitive: Default::default(),
+            boost: Default::default(),
+        }
+    }
+}
+
+declare_flags! {
+    /// Optional operators enabled in the regular expression syntax, defaults to `ALL`
+    ///
+    /// Reference: [Optional operators](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/regexp-syntax.html#regexp-optional-operators)
+    RegexpFlags {
+        ANYSTRING = 0,
+        COMPLEMENT = 1,
+        EMPTY = 2,
+        INTERSECTION = 3,
+        INTERVAL = 4,
+    }
+}
+
+/// Maximum number of automaton states required for the query. Defaults to 10000.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct MaxDeterminizedStates(i32);
+
+impl Default for MaxDeterminizedStates {
+    fn default() -> Self {
+        Self(10000)
+    }
+}
+
+impl EqualsToDefault for MaxDeterminizedStates {}
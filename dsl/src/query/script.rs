@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use crate::{
+    boost::Boost,
+    script::Script,
+    types::EqualsToDefault,
+};
+
+/// Build a script Query, filtering documents on a script returning a boolean,
+/// serialized as `{"script": {"script": {...}}}`
+///
+/// Reference: [Script query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-script-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     query::{script::ScriptQuery, Query},
+///     script::Script,
+/// };
+///
+/// let q = Query::Script(
+///     ScriptQuery::new(
+///         Script::new("doc['num1'].value > params.param1")
+///             .lang("painless")
+///             .param("param1", 5)
+///     )
+/// );
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScriptQuery {
+    script: Script,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl ScriptQuery {
+    pub fn new(script: Script) -> Self {
+        Self {
+            script,
+            boost: Default::default(),
+        }
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
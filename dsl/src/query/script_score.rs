@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    field::Field,
+    script::Script,
+    types::EqualsToDefault,
+};
+
+use super::Query;
+
+/// Build a script_score Query, computing a custom score for the documents returned by `query`
+///
+/// Reference: [Script score query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-script-score-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     clause,
+///     query::{script_score::ScriptScore, Query, QueryValue},
+///     script::Script,
+/// };
+///
+/// let q = Query::ScriptScore(
+///     ScriptScore::new(
+///         clause!(Match, "message", QueryValue::from("elasticsearch")),
+///         Script::new("doc['my-int'].value / 10")
+///     )
+///     .min_score(1.0)
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScriptScore {
+    query: Box<Query>,
+    script: Script,
+    min_score: Option<f32>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl ScriptScore {
+    pub fn new(query: Query, script: Script) -> Self {
+        Self {
+            query: Box::new(query),
+            script,
+            min_score: None,
+            boost: Default::default(),
+        }
+    }
+
+    /// Scores the documents by the cosine similarity between `query_vector`
+    /// and the `dense_vector` field, plus 1 to avoid negative scores
+    ///
+    /// This is an exact, brute-force search over the documents matching
+    /// `query`, which Elasticsearch 7.x supports, unlike the `knn` section.
+    ///
+    /// Reference: [Vector functions](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-script-score-query.html#vector-functions)
+    pub fn cosine_similarity(query: Query, field: impl Into<Field>, query_vector: impl IntoIterator<Item = f32>) -> Self {
+        Self::vector_function(query, field.into(), query_vector, "cosineSimilarity(params.query_vector, params.field) + 1.0")
+    }
+
+    /// Scores the documents by the dot product between `query_vector` and
+    /// the `dense_vector` field, normalized by a sigmoid to avoid negative scores
+    pub fn dot_product(query: Query, field: impl Into<Field>, query_vector: impl IntoIterator<Item = f32>) -> Self {
+        Self::vector_function(
+            query,
+            field.into(),
+            query_vector,
+            "double value = dotProduct(params.query_vector, params.field); return sigmoid(1, Math.E, -value);"
+        )
+    }
+
+    /// Scores the documents by the inverse of the L2 distance between
+    /// `query_vector` and the `dense_vector` field
+    pub fn l2_norm(query: Query, field: impl Into<Field>, query_vector: impl IntoIterator<Item = f32>) -> Self {
+        Self::vector_function(query, field.into(), query_vector, "1 / (1 + l2norm(params.query_vector, params.field))")
+    }
+
+    /// The field is passed as a param, keeping the source constant so that
+    /// the compiled script is cached and reused whatever the field
+    fn vector_function(
+        query: Query,
+        field: Field,
+        query_vector: impl IntoIterator<Item = f32>,
+        source: &'static str,
+    ) -> Self {
+        Self::new(
+            query,
+            Script::new(source)
+                .param("query_vector", query_vector.into_iter().collect::<Vec<_>>())
+                .param("field", field.as_ref().to_owned())
+        )
+    }
+
+    pub fn min_score(mut self, v: f32) -> Self {
+        self.min_score = Some(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
@@ -0,0 +1,154 @@
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    field::{BoostedField, Field},
+    types::{
+        flags::declare_flags,
+        EqualsToDefault,
+    },
+};
+
+use super::{
+    query_string::AnalyzeWildcard,
+    r#match::{Lenient, Operator},
+};
+
+/// Build a simple_query_string Query, which never throws on invalid syntax
+///
+/// Reference: [Simple query string query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-simple-query-string-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::query::{
+///     simple_query_string::{SimpleQueryString, SimpleQueryStringFlags},
+///     Query,
+/// };
+///
+/// let q = Query::SimpleQueryString(
+///     SimpleQueryString::new("foo | bar + baz*")
+///         .field_boost("title", 5.0)
+///         .field("body")
+///         .flags(SimpleQueryStringFlags::OR | SimpleQueryStringFlags::AND | SimpleQueryStringFlags::PREFIX)
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SimpleQueryString {
+    query: Cow<'static, str>,
+    fields: Option<Vec<BoostedField>>,
+    #[serde(default, skip_serializing_if = "Operator::equals_to_default")]
+    default_operator: Operator,
+    analyzer: Option<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "AnalyzeWildcard::equals_to_default")]
+    analyze_wildcard: AnalyzeWildcard,
+    #[serde(default, skip_serializing_if = "SimpleQueryStringFlags::equals_to_default")]
+    flags: SimpleQueryStringFlags,
+    #[serde(default, skip_serializing_if = "Lenient::equals_to_default")]
+    lenient: Lenient,
+    minimum_should_match: Option<Cow<'static, str>>,
+    quote_field_suffix: Option<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl SimpleQueryString {
+    pub fn new(query: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            query: query.into(),
+            fields: None,
+            default_operator: Default::default(),
+            analyzer: None,
+            analyze_wildcard: Default::default(),
+            flags: Default::default(),
+            lenient: Default::default(),
+            minimum_should_match: None,
+            quote_field_suffix: None,
+            boost: Default::default(),
+        }
+    }
+
+    pub fn field(self, v: impl Into<Field>) -> Self {
+        self.push_field(BoostedField::new(v, None))
+    }
+
+    /// Add a field with a per-field boost, serialized as `field^boost`
+    pub fn field_boost(self, v: impl Into<Field>, boost: f32) -> Self {
+        self.push_field(BoostedField::new(v, Some(boost)))
+    }
+
+    fn push_field(mut self, field: BoostedField) -> Self {
+        self.fields.get_or_insert_with(Vec::new).push(field);
+
+        self
+    }
+
+    pub fn default_operator(mut self, v: Operator) -> Self {
+        self.default_operator = v;
+
+        self
+    }
+
+    pub fn analyzer(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.analyzer = Some(v.into());
+
+        self
+    }
+
+    pub fn analyze_wildcard(mut self, v: bool) -> Self {
+        self.analyze_wildcard = AnalyzeWildcard(v);
+
+        self
+    }
+
+    pub fn flags(mut self, v: SimpleQueryStringFlags) -> Self {
+        self.flags = v;
+
+        self
+    }
+
+    pub fn lenient(mut self, v: bool) -> Self {
+        self.lenient = Lenient(v);
+
+        self
+    }
+
+    pub fn minimum_should_match(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.minimum_should_match = Some(v.into());
+
+        self
+    }
+
+    pub fn quote_field_suffix(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.quote_field_suffix = Some(v.into());
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+declare_flags! {
+    /// Operators enabled in the simple query string syntax, defaults to `ALL`
+    ///
+    /// Reference: [Limit operators](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-simple-query-string-query.html#supported-flags)
+    SimpleQueryStringFlags {
+        AND = 0,
+        ESCAPE = 1,
+        FUZZY = 2,
+        NEAR = 3,
+        NOT = 4,
+        OR = 5,
+        PHRASE = 6,
+        PRECEDENCE = 7,
+        PREFIX = 8,
+        SLOP = 9,
+        WHITESPACE = 10,
+    }
+}
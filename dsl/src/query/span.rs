@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    field_masking_span::FieldMaskingSpan,
+    span_containing::SpanContaining,
+    span_first::SpanFirst,
+    span_multi::SpanMulti,
+    span_near::SpanNear,
+    span_not::SpanNot,
+    span_or::SpanOr,
+    span_term::SpanTerm,
+    span_within::SpanWithin,
+    Query,
+};
+
+macro_rules! declare_span_query {
+    ($($clause_name:ident),*) => {
+        /// A span query clause, the only kind of clause accepted inside span queries
+        ///
+        /// Reference: [Span queries](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/span-queries.html)
+        ///
+        /// Only span queries nest in span queries:
+        /// ```compile_fail
+        /// use dsl::{clause, query::span_near::SpanNear};
+        ///
+        /// let q = SpanNear::new().clause(clause!(Term, "field", "value"));
+        /// ```
+        #[derive(Clone, Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        pub enum SpanQuery {
+            $($clause_name($clause_name),)*
+        }
+
+        /// Every span query is a query too
+        impl From<SpanQuery> for Query {
+            fn from(v: SpanQuery) -> Self {
+                match v {
+                    $(SpanQuery::$clause_name(v) => Query::$clause_name(v),)*
+                }
+            }
+        }
+    };
+}
+
+declare_span_query!(
+    FieldMaskingSpan,
+    SpanContaining,
+    SpanFirst,
+    SpanMulti,
+    SpanNear,
+    SpanNot,
+    SpanOr,
+    SpanTerm,
+    SpanWithin
+);
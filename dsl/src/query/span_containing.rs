@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use crate::{
+    boost::Boost,
+    types::EqualsToDefault,
+};
+
+use super::span::SpanQuery;
+
+/// Build a span_containing Query, matching the `big` spans which contain a `little` span
+///
+/// Reference: [Span containing query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-span-containing-query.html)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpanContaining {
+    big: Box<SpanQuery>,
+    little: Box<SpanQuery>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl SpanContaining {
+    pub fn new(big: SpanQuery, little: SpanQuery) -> Self {
+        Self {
+            big: Box::new(big),
+            little: Box::new(little),
+            boost: Default::default(),
+        }
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
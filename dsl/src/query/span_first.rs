@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use crate::{
+    boost::Boost,
+    types::EqualsToDefault,
+};
+
+use super::span::SpanQuery;
+
+/// Build a span_first Query, matching spans ending before the `end` position of the field
+///
+/// Reference: [Span first query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-span-first-query.html)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpanFirst {
+    r#match: Box<SpanQuery>,
+    end: i32,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl SpanFirst {
+    pub fn new(r#match: SpanQuery, end: i32) -> Self {
+        Self {
+            r#match: Box::new(r#match),
+            end,
+            boost: Default::default(),
+        }
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
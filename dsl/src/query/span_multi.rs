@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use crate::{
+    boost::Boost,
+    types::EqualsToDefault,
+};
+
+use super::{
+    fuzzy::Fuzzy,
+    prefix::Prefix,
+    regexp::Regexp,
+    wildcard::Wildcard,
+};
+
+/// Build a span_multi Query, wrapping a multi term query as a span query
+///
+/// Reference: [Span multi-term query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-span-multi-term-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::query::{
+///     prefix::Prefix,
+///     span_multi::{MultiTermQuery, SpanMulti},
+///     Query,
+/// };
+///
+/// let q = Query::SpanMulti(SpanMulti::new(MultiTermQuery::Prefix(Prefix::new("user.id", "ki"))));
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpanMulti {
+    r#match: MultiTermQuery,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl SpanMulti {
+    pub fn new(r#match: MultiTermQuery) -> Self {
+        Self {
+            r#match,
+            boost: Default::default(),
+        }
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+/// The multi term queries accepted by `span_multi`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MultiTermQuery {
+    Fuzzy(Fuzzy),
+    Prefix(Prefix),
+    Regexp(Regexp),
+    Wildcard(Wildcard),
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    types::EqualsToDefault,
+};
+
+use super::{
+    match_phrase::Slop,
+    span::SpanQuery,
+};
+
+/// Build a span_near Query, matching spans which are near one another
+///
+/// Reference: [Span near query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-span-near-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::query::{
+///     span::SpanQuery,
+///     span_near::SpanNear,
+///     span_term::SpanTerm,
+///     Query,
+/// };
+///
+/// let q = Query::SpanNear(
+///     SpanNear::new()
+///         .clause(SpanQuery::SpanTerm(SpanTerm::new("field", "value1")))
+///         .clause(SpanQuery::SpanTerm(SpanTerm::new("field", "value2")))
+///         .slop(12)
+///         .in_order(false)
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpanNear {
+    clauses: Vec<SpanQuery>,
+    #[serde(default, skip_serializing_if = "Slop::equals_to_default")]
+    slop: Slop,
+    in_order: Option<bool>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl SpanNear {
+    pub fn new() -> Self {
+        Self {
+            clauses: vec![],
+            slop: Default::default(),
+            in_order: None,
+            boost: Default::default(),
+        }
+    }
+
+    pub fn clause(mut self, clause: SpanQuery) -> Self {
+        self.clauses.push(clause);
+
+        self
+    }
+
+    /// Maximum number of intervening unmatched positions, defaults to 0
+    pub fn slop(mut self, v: i32) -> Self {
+        self.slop = Slop(v);
+
+        self
+    }
+
+    pub fn in_order(mut self, v: bool) -> Self {
+        self.in_order = Some(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
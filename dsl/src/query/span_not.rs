@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    types::EqualsToDefault,
+};
+
+use super::span::SpanQuery;
+
+/// Build a span_not Query, removing the `include` spans which overlap an `exclude` span
+///
+/// Reference: [Span not query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-span-not-query.html)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpanNot {
+    include: Box<SpanQuery>,
+    exclude: Box<SpanQuery>,
+    pre: Option<i32>,
+    post: Option<i32>,
+    dist: Option<i32>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl SpanNot {
+    pub fn new(include: SpanQuery, exclude: SpanQuery) -> Self {
+        Self {
+            include: Box::new(include),
+            exclude: Box::new(exclude),
+            pre: None,
+            post: None,
+            dist: None,
+            boost: Default::default(),
+        }
+    }
+
+    /// Maximum number of tokens before the `include` span which can't overlap an `exclude` span
+    pub fn pre(mut self, v: i32) -> Self {
+        self.pre = Some(v);
+
+        self
+    }
+
+    /// Maximum number of tokens after the `include` span which can't overlap an `exclude` span
+    pub fn post(mut self, v: i32) -> Self {
+        self.post = Some(v);
+
+        self
+    }
+
+    /// Same as setting both `pre` and `post`
+    pub fn dist(mut self, v: i32) -> Self {
+        self.dist = Some(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use crate::{
+    boost::Boost,
+    types::EqualsToDefault,
+};
+
+use super::span::SpanQuery;
+
+/// Reference: [Span or query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-span-or-query.html)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpanOr {
+    clauses: Vec<SpanQuery>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
+
+impl SpanOr {
+    pub fn new() -> Self {
+        Self {
+            clauses: vec![],
+            boost: Default::default(),
+        }
+    }
+
+    pub fn clause(mut self, clause: SpanQuery) -> Self {
+        self.clauses.push(clause);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
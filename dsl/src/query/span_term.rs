@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
+use crate::{
+    boost::Boost,
+    de::{deserialize_field_entry, ShortOrFull},
+    field::Field,
+    types::EqualsToDefault,
+};
+
+/// Reference: [Span term query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-span-term-query.html)
+#[derive(Clone, Debug)]
+pub struct SpanTerm {
+    field: Field,
+    opts: SpanTermOptions,
+}
+
+impl SpanTerm {
+    pub fn new(field: impl Into<Field>, value: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            field: field.into(),
+            opts: SpanTermOptions {
+                value: value.into(),
+                boost: Default::default(),
+            }
+        }
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.opts.boost = Boost(v);
+
+        self
+    }
+}
+
+impl Serialize for SpanTerm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(Some(1))?;
+        m.serialize_entry(&self.field, &self.opts)?;
+        m.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for SpanTerm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let (field, opts) = deserialize_field_entry::<_, ShortOrFull<Cow<'static, str>, SpanTermOptions>>(deserializer)?;
+
+        let opts = match opts {
+            ShortOrFull::Short(value) => SpanTermOptions {
+                value,
+                boost: Default::default(),
+            },
+            ShortOrFull::Full(opts) => opts,
+        };
+
+        Ok(Self {
+            field,
+            opts,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SpanTermOptions {
+    value: Cow<'static, str>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
@@ -1,5 +1,6 @@
-use std::borrow::Cow;
 use serde::{
+    Deserialize,
+    Deserializer,
     Serialize,
     Serializer,
     ser::SerializeMap,
@@ -7,19 +8,23 @@ use serde::{
 use crate::{
     boost::Boost,
     case_insensitive::CaseInsensitive,
+    de::{deserialize_field_entry, ShortOrFull},
     field::Field,
     types::EqualsToDefault,
 };
 
-pub struct Term<'a> {
-    field: Field<'a>,
-    opts: TermOptions<'a>,
+use super::QueryValue;
+
+#[derive(Clone, Debug)]
+pub struct Term {
+    field: Field,
+    opts: TermOptions,
 }
 
-impl<'a> Term<'a> {
-    pub fn new(field: Field<'a>, value: &'a str) -> Self {
+impl Term {
+    pub fn new(field: impl Into<Field>, value: impl Into<QueryValue>) -> Self {
         Self {
-            field,
+            field: field.into(),
             opts: TermOptions {
                 value: value.into(),
                 boost: Default::default(),
@@ -28,20 +33,20 @@ impl<'a> Term<'a> {
         }
     }
 
-    pub fn boost(&mut self, v: f32) -> &mut Self {
+    pub fn boost(mut self, v: f32) -> Self {
         self.opts.boost = Boost(v);
 
         self
     }
 
-    pub fn case_insensitive(&mut self, v: bool) -> &mut Self {
+    pub fn case_insensitive(mut self, v: bool) -> Self {
         self.opts.case_insensitive = CaseInsensitive(v);
 
         self
     }
 }
 
-impl<'a> Serialize for Term<'a> {
+impl Serialize for Term {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer
@@ -52,11 +57,35 @@ impl<'a> Serialize for Term<'a> {
     }
 }
 
-#[derive(Serialize)]
-pub struct TermOptions<'a> {
-    value: Cow<'a, str>,
-    #[serde(skip_serializing_if = "Boost::equals_to_default")]
+impl<'de> Deserialize<'de> for Term {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let (field, opts) = deserialize_field_entry::<_, ShortOrFull<QueryValue, TermOptions>>(deserializer)?;
+
+        let opts = match opts {
+            ShortOrFull::Short(value) => TermOptions {
+                value,
+                boost: Default::default(),
+                case_insensitive: Default::default(),
+            },
+            ShortOrFull::Full(opts) => opts,
+        };
+
+        Ok(Self {
+            field,
+            opts,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TermOptions {
+    value: QueryValue,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
     boost: Boost,
-    #[serde(skip_serializing_if = "CaseInsensitive::equals_to_default")]
+    #[serde(default, skip_serializing_if = "CaseInsensitive::equals_to_default")]
     case_insensitive: CaseInsensitive,
 }
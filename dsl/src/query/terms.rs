@@ -0,0 +1,168 @@
+use std::borrow::Cow;
+use std::fmt;
+use serde::{
+    de::{self, MapAccess, Visitor},
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    field::Field,
+    types::EqualsToDefault,
+};
+
+use super::QueryValue;
+
+/// Build a terms Query, matching any of the given exact values
+///
+/// Reference: [Terms query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-terms-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::query::{
+///     terms::{Terms, TermsLookup},
+///     Query,
+/// };
+///
+/// let q = Query::Terms(Terms::new("http.response.status_code", [200, 201, 204]));
+///
+/// let q = Query::Terms(
+///     Terms::lookup("color", TermsLookup::new("my-index", "2", "color"))
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Terms {
+    field: Field,
+    values: TermsValues,
+    boost: Boost,
+}
+
+impl Terms {
+    pub fn new<T>(field: impl Into<Field>, values: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<QueryValue>,
+    {
+        Self {
+            field: field.into(),
+            values: TermsValues::Values(values.into_iter().map(Into::into).collect()),
+            boost: Default::default(),
+        }
+    }
+
+    /// Fetch the terms from a field of an existing document
+    pub fn lookup(field: impl Into<Field>, lookup: TermsLookup) -> Self {
+        Self {
+            field: field.into(),
+            values: TermsValues::Lookup(lookup),
+            boost: Default::default(),
+        }
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.boost = Boost(v);
+
+        self
+    }
+}
+
+impl Serialize for Terms {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(None)?;
+        m.serialize_entry(&self.field, &self.values)?;
+        if !self.boost.equals_to_default() {
+            m.serialize_entry("boost", &self.boost)?;
+        }
+        m.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Terms {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        struct TermsVisitor;
+
+        impl<'de> Visitor<'de> for TermsVisitor {
+            type Value = Terms;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map with a single field entry and an optional boost")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut entry: Option<(Field, TermsValues)> = None;
+                let mut boost = Boost::default();
+
+                while let Some(key) = map.next_key::<Cow<'static, str>>()? {
+                    if key == "boost" {
+                        boost = map.next_value()?;
+                    } else if entry.is_none() {
+                        entry = Some((Field::from(key.into_owned()), map.next_value()?));
+                    } else {
+                        return Err(de::Error::custom("expected a single field entry, found more"));
+                    }
+                }
+
+                let (field, values) = entry.ok_or_else(|| de::Error::missing_field("field"))?;
+
+                Ok(Terms {
+                    field,
+                    values,
+                    boost,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(TermsVisitor)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum TermsValues {
+    Values(Vec<QueryValue>),
+    Lookup(TermsLookup),
+}
+
+/// Reference: [Terms lookup](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-terms-query.html#query-dsl-terms-lookup)
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TermsLookup {
+    index: Cow<'static, str>,
+    id: Cow<'static, str>,
+    path: Field,
+    routing: Option<Cow<'static, str>>,
+}
+
+impl TermsLookup {
+    pub fn new(
+        index: impl Into<Cow<'static, str>>,
+        id: impl Into<Cow<'static, str>>,
+        path: impl Into<Field>,
+    ) -> Self {
+        Self {
+            index: index.into(),
+            id: id.into(),
+            path: path.into(),
+            routing: None,
+        }
+    }
+
+    pub fn routing(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.routing = Some(v.into());
+
+        self
+    }
+}
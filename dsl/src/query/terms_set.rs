@@ -0,0 +1,111 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer, ser::SerializeMap};
+use serde_with::skip_serializing_none;
+use crate::{
+    boost::Boost,
+    de::deserialize_field_entry,
+    field::Field,
+    script::Script,
+    types::EqualsToDefault,
+};
+
+use super::QueryValue;
+
+/// Build a terms_set Query, matching a computed minimum number of the given terms
+///
+/// Reference: [Terms set query](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/query-dsl-terms-set-query.html)
+///
+/// # Example
+/// ```
+/// use dsl::{
+///     query::{terms_set::TermsSet, Query},
+///     script::Script,
+/// };
+///
+/// let q = Query::TermsSet(
+///     TermsSet::new("programming_languages", ["c++", "java", "php"])
+///         .minimum_should_match_field("required_matches")
+/// );
+///
+/// let q = Query::TermsSet(
+///     TermsSet::new("programming_languages", ["c++", "java", "php"])
+///         .minimum_should_match_script(
+///             Script::new("Math.min(params.num_terms, doc['required_matches'].value)")
+///         )
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct TermsSet {
+    field: Field,
+    opts: TermsSetOptions,
+}
+
+impl TermsSet {
+    pub fn new<T>(field: impl Into<Field>, terms: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<QueryValue>,
+    {
+        Self {
+            field: field.into(),
+            opts: TermsSetOptions {
+                terms: terms.into_iter().map(Into::into).collect(),
+                minimum_should_match_field: None,
+                minimum_should_match_script: None,
+                boost: Default::default(),
+            },
+        }
+    }
+
+    pub fn minimum_should_match_field(mut self, v: impl Into<Field>) -> Self {
+        self.opts.minimum_should_match_field = Some(v.into());
+
+        self
+    }
+
+    pub fn minimum_should_match_script(mut self, v: Script) -> Self {
+        self.opts.minimum_should_match_script = Some(v);
+
+        self
+    }
+
+    pub fn boost(mut self, v: f32) -> Self {
+        self.opts.boost = Boost(v);
+
+        self
+    }
+}
+
+impl Serialize for TermsSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let mut m = serializer.serialize_map(Some(1))?;
+        m.serialize_entry(&self.field, &self.opts)?;
+        m.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for TermsSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let (field, opts) = deserialize_field_entry(deserializer)?;
+
+        Ok(Self {
+            field,
+            opts,
+        })
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TermsSetOptions {
+    terms: Vec<QueryValue>,
+    minimum_should_match_field: Option<Field>,
+    minimum_should_match_script: Option<Script>,
+    #[serde(default, skip_serializing_if = "Boost::equals_to_default")]
+    boost: Boost,
+}
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 use serde::{
+    Deserialize,
+    Deserializer,
     Serialize,
     Serializer,
     ser::SerializeMap,
@@ -8,18 +10,23 @@ use serde_with::skip_serializing_none;
 use crate::{
     boost::Boost,
     case_insensitive::CaseInsensitive,
+    de::{deserialize_field_entry, ShortOrFull},
     field::Field,
     types::EqualsToDefault,
 };
 
-#[derive(Clone)]
-pub struct Wildcard<'a> {
-    field: Field<'a>,
-    opts: WildcardOptions<'a>,
+#[derive(Clone, Debug)]
+pub struct Wildcard {
+    field: Field,
+    opts: WildcardOptions,
 }
 
-impl<'a> Wildcard<'a> {
-    pub fn new(field: &'a str, value: &'a str, wildcard: &'a str) -> Self {
+impl Wildcard {
+    pub fn new(
+        field: impl Into<Field>,
+        value: impl Into<Cow<'static, str>>,
+        wildcard: impl Into<Cow<'static, str>>,
+    ) -> Self {
         Self {
             field: field.into(),
             opts: WildcardOptions {
@@ -32,26 +39,26 @@ impl<'a> Wildcard<'a> {
         }
     }
 
-    pub fn boost(&mut self, boost: f32) -> &mut Self {
+    pub fn boost(mut self, boost: f32) -> Self {
         self.opts.boost = Boost(boost);
 
         self
     }
 
-    pub fn case_insensitive(&mut self, v: bool) -> &mut Self {
+    pub fn case_insensitive(mut self, v: bool) -> Self {
         self.opts.case_insensitive = CaseInsensitive(v);
 
         self
     }
 
-    pub fn rewrite(&mut self, v: &'a str) -> &mut Self {
+    pub fn rewrite(mut self, v: impl Into<Cow<'static, str>>) -> Self {
         self.opts.rewrite = Some(v.into());
 
         self
     }
 }
 
-impl<'a> Serialize for Wildcard<'a> {
+impl Serialize for Wildcard {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer
@@ -62,14 +69,75 @@ impl<'a> Serialize for Wildcard<'a> {
     }
 }
 
+impl<'de> Deserialize<'de> for Wildcard {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let (field, opts) = deserialize_field_entry::<_, ShortOrFull<Cow<'static, str>, WildcardOptions>>(deserializer)?;
+
+        let opts = match opts {
+            ShortOrFull::Short(value) => WildcardOptions {
+                boost: Default::default(),
+                case_insensitive: Default::default(),
+                rewrite: None,
+                value: value.clone(),
+                wildcard: value,
+            },
+            ShortOrFull::Full(opts) => opts,
+        };
+
+        Ok(Self {
+            field,
+            opts,
+        })
+    }
+}
+
 #[skip_serializing_none]
-#[derive(Clone, Serialize)]
-struct WildcardOptions<'a> {
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "WildcardOptionsRepr")]
+struct WildcardOptions {
     #[serde(skip_serializing_if = "Boost::equals_to_default")]
     boost: Boost,
     #[serde(skip_serializing_if = "CaseInsensitive::equals_to_default")]
     case_insensitive: CaseInsensitive,
-    rewrite: Option<Cow<'a, str>>,
-    value: Cow<'a, str>,
-    wildcard: Cow<'a, str>,
+    rewrite: Option<Cow<'static, str>>,
+    value: Cow<'static, str>,
+    wildcard: Cow<'static, str>,
+}
+
+/// `value` and `wildcard` are aliases of each other in Elasticsearch, so
+/// either one is enough to restore both of them.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WildcardOptionsRepr {
+    #[serde(default)]
+    boost: Boost,
+    #[serde(default)]
+    case_insensitive: CaseInsensitive,
+    rewrite: Option<Cow<'static, str>>,
+    value: Option<Cow<'static, str>>,
+    wildcard: Option<Cow<'static, str>>,
+}
+
+impl TryFrom<WildcardOptionsRepr> for WildcardOptions {
+    type Error = &'static str;
+
+    fn try_from(repr: WildcardOptionsRepr) -> Result<Self, Self::Error> {
+        let (value, wildcard) = match (repr.value, repr.wildcard) {
+            (Some(value), Some(wildcard)) => (value, wildcard),
+            (Some(value), None) => (value.clone(), value),
+            (None, Some(wildcard)) => (wildcard.clone(), wildcard),
+            (None, None) => return Err("missing field `value` or `wildcard`"),
+        };
+
+        Ok(Self {
+            boost: repr.boost,
+            case_insensitive: repr.case_insensitive,
+            rewrite: repr.rewrite,
+            value,
+            wildcard,
+        })
+    }
 }
@@ -0,0 +1,124 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use crate::query::QueryValue;
+
+/// An inline or a stored script, accepted by the `script` and `script_score`
+/// queries, the `script_score` function and the `terms_set` query
+///
+/// Reference: [How to write scripts](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/modules-scripting-using.html)
+///
+/// # Example
+/// ```
+/// use dsl::script::Script;
+///
+/// let inline = Script::new("doc['my_field'].value * params.multiplier")
+///     .lang("painless")
+///     .param("multiplier", 2)
+///     .param("tags", vec!["a", "b"]);
+///
+/// let stored = Script::stored("calculate-score").param("my_modifier", 2);
+/// ```
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(from = "ScriptRepr")]
+pub struct Script {
+    #[serde(flatten)]
+    code: ScriptCode,
+    lang: Option<Cow<'static, str>>,
+    params: Option<BTreeMap<Cow<'static, str>, ScriptParam>>,
+}
+
+impl Script {
+    /// An inline script, serialized as `{"source": "..."}`
+    pub fn new(source: impl Into<Cow<'static, str>>) -> Self {
+        Self::with(ScriptCode::Source(source.into()))
+    }
+
+    /// A stored script, serialized as `{"id": "..."}`
+    pub fn stored(id: impl Into<Cow<'static, str>>) -> Self {
+        Self::with(ScriptCode::Id(id.into()))
+    }
+
+    fn with(code: ScriptCode) -> Self {
+        Self {
+            code,
+            lang: None,
+            params: None,
+        }
+    }
+
+    pub fn lang(mut self, v: impl Into<Cow<'static, str>>) -> Self {
+        self.lang = Some(v.into());
+
+        self
+    }
+
+    pub fn param(mut self, name: impl Into<Cow<'static, str>>, value: impl Into<ScriptParam>) -> Self {
+        self.params.get_or_insert_with(BTreeMap::new).insert(name.into(), value.into());
+
+        self
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ScriptCode {
+    Source(Cow<'static, str>),
+    Id(Cow<'static, str>),
+}
+
+/// Elasticsearch also accepts an inline script written as a plain string,
+/// e.g. `"script": "doc['my_field'].value"`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ScriptRepr {
+    Short(Cow<'static, str>),
+    Full {
+        #[serde(flatten)]
+        code: ScriptCode,
+        lang: Option<Cow<'static, str>>,
+        params: Option<BTreeMap<Cow<'static, str>, ScriptParam>>,
+    },
+}
+
+impl From<ScriptRepr> for Script {
+    fn from(repr: ScriptRepr) -> Self {
+        match repr {
+            ScriptRepr::Short(source) => Self::new(source),
+            ScriptRepr::Full { code, lang, params } => Self {
+                code,
+                lang,
+                params,
+            },
+        }
+    }
+}
+
+/// The value of a script parameter, a single value or a list of values
+#[allow(dead_code)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ScriptParam {
+    Value(QueryValue),
+    List(Vec<QueryValue>),
+}
+
+impl<T> From<T> for ScriptParam
+where
+    T: Into<QueryValue>,
+{
+    fn from(v: T) -> Self {
+        Self::Value(v.into())
+    }
+}
+
+impl<T> From<Vec<T>> for ScriptParam
+where
+    T: Into<QueryValue>,
+{
+    fn from(v: Vec<T>) -> Self {
+        Self::List(v.into_iter().map(Into::into).collect())
+    }
+}
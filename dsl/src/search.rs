@@ -1,36 +1,162 @@
-use serde::Serialize;
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
-use crate::sort::Sort;
+use crate::{
+    aggregation::{Aggregation, Aggregations},
+    field::Field,
+    knn::Knn,
+    sort::Sort,
+    source::SourceFilter,
+};
 
 use super::query::Query;
 
 #[skip_serializing_none]
-#[derive(Serialize)]
-pub struct Search<'a> {
-    query: Option<&'a Query<'a>>,
-    sort: Option<&'a Sort<'a>>,
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Search {
+    query: Option<Query>,
+    from: Option<u32>,
+    size: Option<u32>,
+    sort: Option<Sort>,
+    _source: Option<SourceFilter>,
+    #[serde(
+        default,
+        serialize_with = "crate::knn::serialize_knn",
+        deserialize_with = "crate::de::one_or_many"
+    )]
+    knn: Option<Vec<Knn>>,
+    #[serde(alias = "aggregations")]
+    aggs: Option<Aggregations>,
 }
 
-impl<'a> Search<'a> {
+impl Search {
     pub fn new() -> Self {
         Self {
             query: None,
+            from: None,
+            size: None,
             sort: None,
+            _source: None,
+            knn: None,
+            aggs: None,
         }
     }
 
-    pub fn query(&mut self, query: &'a Query) -> &mut Self {
+    pub fn query(mut self, query: Query) -> Self {
         self.query = Some(query);
 
         self
     }
 
-    pub fn sort(&mut self, sort: &'a Sort<'a>) -> &mut Self {
+    /// The offset of the first hit, defaults to 0
+    pub fn from(mut self, v: u32) -> Self {
+        self.from = Some(v);
+
+        self
+    }
+
+    /// The number of hits to return, defaults to 10
+    pub fn size(mut self, v: u32) -> Self {
+        self.size = Some(v);
+
+        self
+    }
+
+    pub fn sort(mut self, sort: Sort) -> Self {
         self.sort = Some(sort);
 
         self
     }
 
+    /// Whether to return the `_source` of the hits
+    pub fn source(mut self, v: bool) -> Self {
+        self._source = Some(SourceFilter::Enabled(v));
+
+        self
+    }
+
+    /// Only return these fields of the `_source` of the hits
+    pub fn source_includes<T>(mut self, v: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<Field>,
+    {
+        self._source = Some(SourceFilter::Includes(v.into_iter().map(Into::into).collect()));
+
+        self
+    }
+
+    /// Adds a kNN clause, the scores of several clauses are summed up
+    pub fn knn(mut self, knn: Knn) -> Self {
+        self.knn.get_or_insert_with(Vec::new).push(knn);
+
+        self
+    }
+
+    /// Adds a named aggregation
+    pub fn agg(mut self, name: impl Into<Cow<'static, str>>, agg: Aggregation) -> Self {
+        self.aggs = Some(self.aggs.take().unwrap_or_else(Aggregations::new).agg(name, agg));
+
+        self
+    }
+
+    /// Checks that the `buckets_path` of the pipeline aggregations lead to
+    /// existing aggregations, see [`Aggregations::validate`]
+    pub fn validate(&self) -> Result<(), String> {
+        self.aggs.as_ref().map_or(Ok(()), Aggregations::validate)
+    }
+
+    /// The search of the page following `response` of the composite aggregation
+    /// `name`, taking the `after_key` of its results, `None` once all the
+    /// buckets were returned
+    ///
+    /// # Example
+    /// ```
+    /// use dsl::{
+    ///     aggregation::{
+    ///         composite::{Composite, CompositeSource, TermsSource},
+    ///         Aggregation,
+    ///     },
+    ///     search::Search,
+    /// };
+    ///
+    /// let search = Search::new().agg(
+    ///     "pairs",
+    ///     Aggregation::Composite(
+    ///         Composite::new()
+    ///             .source("user", CompositeSource::Terms(TermsSource::new("user.id")))
+    ///             .source("host", CompositeSource::Terms(TermsSource::new("host.name")))
+    ///     )
+    /// );
+    ///
+    /// let response = serde_json::json!({
+    ///     "aggregations": {
+    ///         "pairs": {
+    ///             "after_key": {"user": "kimchy", "host": "web-01"},
+    ///             "buckets": []
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// let next = search.next_composite_page("pairs", &response).unwrap();
+    ///
+    /// assert_eq!(
+    ///     next.build()["aggs"]["pairs"]["composite"]["after"],
+    ///     serde_json::json!({"user": "kimchy", "host": "web-01"})
+    /// );
+    /// ```
+    pub fn next_composite_page(&self, name: &str, response: &serde_json::Value) -> Option<Self> {
+        let after_key = response.get("aggregations")?.get(name)?.get("after_key")?.as_object()?;
+        let mut search = self.clone();
+
+        match search.aggs.as_mut()?.get_mut(name)? {
+            Aggregation::Composite(composite) => *composite = composite.clone().after(after_key.clone()),
+            _ => return None,
+        }
+
+        Some(search)
+    }
+
     pub fn build(&self) -> serde_json::Value {
         serde_json::to_value(self).unwrap()
     }
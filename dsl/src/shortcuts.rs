@@ -1,16 +1,10 @@
-use std::marker::PhantomData;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize)]
-pub struct Empty<'a> {
-    #[serde(skip_serializing)]
-    _unused: PhantomData<&'a ()>,
-}
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Empty {}
 
-impl<'a> Empty<'a> {
+impl Empty {
     pub fn new() -> Self {
-        Self {
-            _unused: PhantomData,
-        }
+        Self {}
     }
 }
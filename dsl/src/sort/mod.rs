@@ -2,6 +2,8 @@ pub mod nested;
 
 use std::borrow::Cow;
 use serde::{
+    Deserialize,
+    Deserializer,
     Serialize,
     Serializer,
     ser::{
@@ -9,89 +11,109 @@ use serde::{
         SerializeSeq,
     },
 };
-use serde_with::{SerializeDisplay, skip_serializing_none};
-use macros::DisplayCase;
+use serde_with::{DeserializeFromStr, SerializeDisplay, skip_serializing_none};
+use macros::{DisplayCase, FromStrCase};
 use nested::SortNested;
-use crate::field::Field;
+use crate::{
+    de::{OneOrMany, ShortOrFull},
+    field::{Field, FieldEntry},
+};
 
-#[derive(Clone)]
-pub struct Sort<'a> {
-    clauses: Vec<&'a SortClause<'a>>,
+#[derive(Clone, Debug)]
+pub struct Sort {
+    clauses: Vec<SortClause>,
 }
 
-impl<'a> Sort<'a> {
+impl Sort {
     pub fn new() -> Self {
         Self {
             clauses: vec![],
         }
     }
 
-    pub fn sort(&mut self, sort_clause: &'a SortClause<'a>) -> &mut Self {
+    pub fn sort(mut self, sort_clause: SortClause) -> Self {
         self.clauses.push(sort_clause);
 
         self
     }
 }
 
-impl<'a> Serialize for Sort<'a> {
+impl Serialize for Sort {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer
     {
         let mut seq = serializer.serialize_seq(Some(self.clauses.len()))?;
         for clause in &self.clauses {
-            seq.serialize_element(&clause)?;
+            seq.serialize_element(clause)?;
         }
         seq.end()
     }
 }
 
-#[derive(Clone)]
-pub struct SortClause<'a> {
-    field: Field<'a>,
-    opts: SortOptions<'a>,
+/// Accepts both `"sort": [...]` and a single sort clause, e.g. `"sort": "@timestamp"`
+impl<'de> Deserialize<'de> for Sort {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let clauses = match OneOrMany::deserialize(deserializer)? {
+            OneOrMany::One(clause) => vec![clause],
+            OneOrMany::Many(clauses) => clauses,
+        };
+
+        Ok(Self {
+            clauses,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SortClause {
+    field: Field,
+    opts: SortOptions,
 }
 
-impl<'a> SortClause<'a> {
-    pub fn new(field: &'a str) -> Self {
+impl SortClause {
+    pub fn new(field: impl Into<Field>) -> Self {
         Self {
             field: field.into(),
             opts: Default::default(),
         }
     }
 
-    pub fn format(&mut self, format: &'a str) -> &mut Self {
+    pub fn format(mut self, format: impl Into<Cow<'static, str>>) -> Self {
         self.opts.format = Some(format.into());
 
         self
     }
 
-    pub fn order(&mut self, order: Order) -> &mut Self {
+    pub fn order(mut self, order: Order) -> Self {
         self.opts.order = Some(order);
 
         self
     }
 
-    pub fn mode(&mut self, mode: Mode) -> &mut Self {
+    pub fn mode(mut self, mode: Mode) -> Self {
         self.opts.mode = Some(mode);
 
         self
     }
 
-    pub fn numberic_type(&mut self, numberic_type: NumbericType) -> &mut Self {
+    pub fn numberic_type(mut self, numberic_type: NumbericType) -> Self {
         self.opts.numberic_type = Some(numberic_type);
 
         self
     }
 
-    pub fn nested(&mut self, nested: SortNested<'a>) -> &mut Self {
-        self.opts.nested = Some(nested.clone());
+    pub fn nested(mut self, nested: SortNested) -> Self {
+        self.opts.nested = Some(nested);
 
         self
     }
 }
 
-impl<'a> Serialize for SortClause<'a> {
+impl Serialize for SortClause {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -102,17 +124,46 @@ impl<'a> Serialize for SortClause<'a> {
     }
 }
 
+/// Accepts the three forms of a sort clause: `"field"`, `{"field": "asc"}`
+/// and `{"field": {"order": "asc", ...}}`
+impl<'de> Deserialize<'de> for SortClause {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let entry = ShortOrFull::<Field, FieldEntry<ShortOrFull<Order, SortOptions>>>::deserialize(deserializer)?;
+
+        let (field, opts) = match entry {
+            ShortOrFull::Short(field) => (field, Default::default()),
+            ShortOrFull::Full(FieldEntry { field, value: ShortOrFull::Short(order) }) => (
+                field,
+                SortOptions {
+                    order: Some(order),
+                    ..Default::default()
+                },
+            ),
+            ShortOrFull::Full(FieldEntry { field, value: ShortOrFull::Full(opts) }) => (field, opts),
+        };
+
+        Ok(Self {
+            field,
+            opts,
+        })
+    }
+}
+
 #[skip_serializing_none]
-#[derive(Clone, Serialize)]
-struct SortOptions<'a> {
-    format: Option<Cow<'a, str>>,
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct SortOptions {
+    format: Option<Cow<'static, str>>,
     order: Option<Order>,
     mode: Option<Mode>,
     numberic_type: Option<NumbericType>,
-    nested: Option<SortNested<'a>>,
+    nested: Option<SortNested>,
 }
 
-impl<'a> Default for SortOptions<'a> {
+impl Default for SortOptions {
     fn default() -> Self {
         Self {
             format: None,
@@ -125,7 +176,7 @@ impl<'a> Default for SortOptions<'a> {
 }
 
 #[allow(dead_code)]
-#[derive(Clone, DisplayCase, SerializeDisplay)]
+#[derive(Clone, Debug, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
 #[display_case(case = "lowercase")]
 pub enum Order {
     Asc,
@@ -133,7 +184,7 @@ pub enum Order {
 }
 
 #[allow(dead_code)]
-#[derive(Clone, DisplayCase, SerializeDisplay)]
+#[derive(Clone, Debug, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
 #[display_case(case = "lowercase")]
 pub enum Mode {
     Min,
@@ -144,7 +195,7 @@ pub enum Mode {
 }
 
 #[allow(dead_code)]
-#[derive(Clone, DisplayCase, SerializeDisplay)]
+#[derive(Clone, Debug, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
 #[display_case(case = "snakecase")]
 pub enum NumbericType {
     Double,
@@ -1,19 +1,21 @@
 use std::borrow::Cow;
-use std::rc::Rc;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
-use crate::query::LeafClause;
+use crate::query::Query;
 
-#[derive(Clone, Serialize)]
-pub struct SortNested<'a> {
-    path: Cow<'a, str>,
-    filter: Option<LeafClause<'a>>,
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SortNested {
+    path: Cow<'static, str>,
+    filter: Option<Query>,
     max_children: Option<i32>,
-    nested: Option<Rc<SortNested<'a>>>,
+    nested: Option<Box<SortNested>>,
 }
 
-impl<'a> SortNested<'a> {
-    pub fn new(path: &'a str) -> Self {
+impl SortNested {
+    pub fn new(path: impl Into<Cow<'static, str>>) -> Self {
         Self {
             path: path.into(),
             filter: None,
@@ -22,20 +24,20 @@ impl<'a> SortNested<'a> {
         }
     }
 
-    pub fn filter(&mut self, filter: LeafClause<'a>) -> &mut Self {
+    pub fn filter(mut self, filter: Query) -> Self {
         self.filter = Some(filter);
 
         self
     }
 
-    pub fn max_children(&mut self, v: i32) -> &mut Self {
+    pub fn max_children(mut self, v: i32) -> Self {
         self.max_children = Some(v);
 
         self
     }
 
-    pub fn nested(&mut self, nested: SortNested<'a>) -> &mut Self {
-        self.nested = Some(Rc::new(nested));
+    pub fn nested(mut self, nested: SortNested) -> Self {
+        self.nested = Some(Box::new(nested));
 
         self
     }
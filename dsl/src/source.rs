@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use crate::field::Field;
+
+/// The `_source` of the hits, either returned or not, or only these fields
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum SourceFilter {
+    Enabled(bool),
+    Includes(Vec<Field>),
+}
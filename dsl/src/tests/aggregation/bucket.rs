@@ -0,0 +1,380 @@
+#[test]
+fn test_terms() {
+    use crate::{
+        aggregation::{
+            multi_terms::{MultiTerms, MultiTermsSource},
+            significant_terms::SignificantTerms,
+            terms::{Terms, TermsExclude, TermsInclude},
+            Aggregation,
+        },
+        clause,
+        sort::Order,
+    };
+
+    for (agg, s) in [
+        (
+            Aggregation::Terms(
+                Terms::new()
+                    .field("genre")
+                    .size(5)
+                    .min_doc_count(2)
+                    .missing("N/A")
+                    .order("_count", Order::Desc)
+                    .order("_key", Order::Asc)
+                    .include(TermsInclude::pattern(".*sport.*"))
+                    .exclude(TermsExclude::values(["water_sports", "winter_sports"]))
+            ),
+            concat!(
+                r#"{"terms":{"field":"genre","missing":"N/A","size":5,"min_doc_count":2,"#,
+                r#""order":[{"_count":"desc"},{"_key":"asc"}],"include":".*sport.*","#,
+                r#""exclude":["water_sports","winter_sports"]}}"#,
+            ),
+        ),
+        (
+            Aggregation::Terms(Terms::new().field("account_id").include(TermsInclude::partition(0, 20))),
+            r#"{"terms":{"field":"account_id","include":{"partition":0,"num_partitions":20}}}"#,
+        ),
+        (
+            Aggregation::SignificantTerms(
+                SignificantTerms::new("crime_type").min_doc_count(5).background_filter(clause!(Term, "city", "madrid"))
+            ),
+            r#"{"significant_terms":{"field":"crime_type","min_doc_count":5,"background_filter":{"term":{"city":{"value":"madrid"}}}}}"#,
+        ),
+        (
+            Aggregation::MultiTerms(
+                MultiTerms::new()
+                    .term(MultiTermsSource::new("genre"))
+                    .term(MultiTermsSource::new("product").missing("unknown"))
+                    .order("_count", Order::Desc)
+            ),
+            r#"{"multi_terms":{"terms":[{"field":"genre"},{"field":"product","missing":"unknown"}],"order":[{"_count":"desc"}]}}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+
+        let agg: Aggregation = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+    }
+
+    for (s, expected) in [
+        (
+            r#"{"terms":{"field":"genre","order":{"_count":"desc"}}}"#,
+            r#"{"terms":{"field":"genre","order":[{"_count":"desc"}]}}"#,
+        ),
+        (
+            r#"{"histogram":{"field":"price","interval":50,"order":{"_key":"desc"}}}"#,
+            r#"{"histogram":{"field":"price","interval":50,"order":[{"_key":"desc"}]}}"#,
+        ),
+        (
+            r#"{"date_histogram":{"field":"date","calendar_interval":"month","order":{"_key":"desc"}}}"#,
+            r#"{"date_histogram":{"field":"date","calendar_interval":"month","order":[{"_key":"desc"}]}}"#,
+        ),
+        (
+            r#"{"multi_terms":{"terms":[{"field":"genre"}],"order":{"_count":"desc"}}}"#,
+            r#"{"multi_terms":{"terms":[{"field":"genre"}],"order":[{"_count":"desc"}]}}"#,
+        ),
+    ] {
+        let agg: Aggregation = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&agg).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_histogram() {
+    use crate::{
+        aggregation::{
+            histogram::{CalendarInterval, DateHistogram, ExtendedBounds, Histogram},
+            Aggregation,
+        },
+        query::QueryValue,
+        types::time::{TimeUnit, TimeValue},
+    };
+
+    for (agg, s) in [
+        (
+            Aggregation::Histogram(
+                Histogram::new(50)
+                    .field("price")
+                    .min_doc_count(0)
+                    .extended_bounds(ExtendedBounds::new(0, 500))
+            ),
+            r#"{"histogram":{"field":"price","interval":50,"min_doc_count":0,"extended_bounds":{"min":0,"max":500}}}"#,
+        ),
+        (
+            Aggregation::DateHistogram(
+                DateHistogram::calendar(CalendarInterval::Month)
+                    .field("date")
+                    .format("yyyy-MM-dd")
+                    .time_zone("-01:00")
+                    .min_doc_count(0)
+                    .extended_bounds(ExtendedBounds::<QueryValue>::new("2020-01-01", "2020-12-31"))
+            ),
+            concat!(
+                r#"{"date_histogram":{"field":"date","calendar_interval":"month","format":"yyyy-MM-dd","#,
+                r#""time_zone":"-01:00","min_doc_count":0,"extended_bounds":{"min":"2020-01-01","max":"2020-12-31"}}}"#,
+            ),
+        ),
+        (
+            Aggregation::DateHistogram(
+                DateHistogram::fixed(TimeValue::new(30, TimeUnit::Days)).field("date").offset("+6h")
+            ),
+            r#"{"date_histogram":{"field":"date","fixed_interval":"30d","offset":"+6h"}}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+
+        let agg: Aggregation = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+    }
+
+    assert!(serde_json::from_str::<Aggregation>(r#"{"date_histogram":{"field":"date","calendar_interval":"fortnight"}}"#).is_err());
+    assert!(
+        serde_json::from_str::<Aggregation>(
+            r#"{"date_histogram":{"field":"date","calendar_interval":"month","fixed_interval":"30d"}}"#
+        )
+        .is_err()
+    );
+
+    for (shorthand, interval) in [
+        ("1m", CalendarInterval::Minute),
+        ("1h", CalendarInterval::Hour),
+        ("1d", CalendarInterval::Day),
+        ("1w", CalendarInterval::Week),
+        ("1M", CalendarInterval::Month),
+        ("1q", CalendarInterval::Quarter),
+        ("1y", CalendarInterval::Year),
+    ] {
+        assert_eq!(shorthand.parse::<CalendarInterval>(), Ok(interval));
+    }
+
+    let agg: Aggregation = serde_json::from_str(r#"{"date_histogram":{"field":"date","calendar_interval":"1M"}}"#).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&agg).unwrap(),
+        r#"{"date_histogram":{"field":"date","calendar_interval":"month"}}"#
+    );
+}
+
+#[test]
+fn test_range() {
+    use crate::aggregation::{
+        range::{DateRange, Range, RangeBucket},
+        Aggregation,
+    };
+
+    for (agg, s) in [
+        (
+            Aggregation::Range(
+                Range::new()
+                    .field("price")
+                    .range(RangeBucket::new().to(100.0))
+                    .range(RangeBucket::new().from(100.0).to(200.0))
+                    .range(RangeBucket::new().key("expensive").from(200.0))
+            ),
+            r#"{"range":{"field":"price","ranges":[{"to":100.0},{"from":100.0,"to":200.0},{"key":"expensive","from":200.0}]}}"#,
+        ),
+        (
+            Aggregation::DateRange(
+                DateRange::new()
+                    .field("date")
+                    .format("MM-yyyy")
+                    .time_zone("CET")
+                    .range(RangeBucket::new().to("now-10M/M"))
+                    .range(RangeBucket::new().from("now-10M/M"))
+                    .keyed(true)
+            ),
+            concat!(
+                r#"{"date_range":{"field":"date","format":"MM-yyyy","time_zone":"CET","#,
+                r#""ranges":[{"to":"now-10M/M"},{"from":"now-10M/M"}],"keyed":true}}"#,
+            ),
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+
+        let agg: Aggregation = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+    }
+}
+
+#[test]
+fn test_filter() {
+    use crate::{
+        aggregation::{
+            filter::{Filter, Filters},
+            missing::Missing,
+            Aggregation,
+        },
+        clause,
+        query::QueryValue,
+    };
+
+    for (agg, s) in [
+        (
+            Aggregation::Filter(Filter::new(clause!(Term, "type", "t-shirt"))),
+            r#"{"filter":{"term":{"type":{"value":"t-shirt"}}}}"#,
+        ),
+        (
+            Aggregation::Filters(
+                Filters::new()
+                    .filter("warnings", clause!(Match, "body", QueryValue::from("warn")))
+                    .filter("errors", clause!(Match, "body", QueryValue::from("error")))
+                    .filter("warnings", clause!(Match, "body", QueryValue::from("warning")))
+                    .other_bucket_key("other_messages")
+            ),
+            concat!(
+                r#"{"filters":{"filters":{"warnings":{"match":{"body":{"query":"warning"}}},"#,
+                r#""errors":{"match":{"body":{"query":"error"}}}},"other_bucket_key":"other_messages"}}"#,
+            ),
+        ),
+        (
+            Aggregation::Filters(
+                Filters::anonymous([
+                    clause!(Match, "body", QueryValue::from("error")),
+                    clause!(Match, "body", QueryValue::from("warning")),
+                ])
+                .other_bucket(true)
+            ),
+            concat!(
+                r#"{"filters":{"filters":[{"match":{"body":{"query":"error"}}},"#,
+                r#"{"match":{"body":{"query":"warning"}}}],"other_bucket":true}}"#,
+            ),
+        ),
+        (Aggregation::Missing(Missing::new("price")), r#"{"missing":{"field":"price"}}"#),
+    ] {
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+
+        let agg: Aggregation = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+    }
+}
+
+#[test]
+fn test_sub_aggregations() {
+    use crate::{
+        aggregation::{
+            filter::Filter,
+            histogram::{CalendarInterval, DateHistogram},
+            metric::{Avg, Max},
+            terms::Terms,
+            Aggregation,
+        },
+        clause,
+        search::Search,
+    };
+
+    let search = Search::new().agg(
+        "shirts",
+        Aggregation::Filter(
+            Filter::new(clause!(Term, "type", "t-shirt")).agg(
+                "per_month",
+                Aggregation::DateHistogram(
+                    DateHistogram::calendar(CalendarInterval::Month)
+                        .field("date")
+                        .agg(
+                            "colors",
+                            Aggregation::Terms(
+                                Terms::new()
+                                    .field("color")
+                                    .agg("avg_price", Aggregation::Avg(Avg::new().field("price")))
+                                    .agg("max_price", Aggregation::Max(Max::new().field("price")))
+                            )
+                        )
+                )
+            )
+        )
+    );
+
+    let s = serde_json::to_string(&search).unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"aggs":{"shirts":{"filter":{"term":{"type":{"value":"t-shirt"}}},"aggs":{"#,
+            r#""per_month":{"date_histogram":{"field":"date","calendar_interval":"month"},"aggs":{"#,
+            r#""colors":{"terms":{"field":"color"},"aggs":{"#,
+            r#""avg_price":{"avg":{"field":"price"}},"max_price":{"max":{"field":"price"}}}}}}}}}}"#,
+        )
+    );
+
+    let search: Search = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&search).unwrap(), s);
+
+    let agg: Aggregation = serde_json::from_str(
+        r#"{"terms":{"field":"color"},"aggregations":{"avg_price":{"avg":{"field":"price"}}}}"#
+    )
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&agg).unwrap(),
+        r#"{"terms":{"field":"color"},"aggs":{"avg_price":{"avg":{"field":"price"}}}}"#
+    );
+    assert!(agg.aggs().and_then(|aggs| aggs.get("avg_price")).is_some());
+}
+
+#[test]
+fn test_meta() {
+    use serde_json::json;
+    use crate::aggregation::{
+        metric::Avg,
+        terms::Terms,
+        Aggregation,
+    };
+
+    let meta = json!({"color": "blue"}).as_object().unwrap().clone();
+
+    let agg = Aggregation::Terms(
+        Terms::new()
+            .field("color")
+            .meta(meta.clone())
+            .agg("avg_price", Aggregation::Avg(Avg::new().field("price").meta(meta)))
+    );
+
+    let s = serde_json::to_string(&agg).unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"terms":{"field":"color"},"meta":{"color":"blue"},"#,
+            r#""aggs":{"avg_price":{"avg":{"field":"price"},"meta":{"color":"blue"}}}}"#,
+        )
+    );
+
+    let agg: Aggregation = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+    assert_eq!(agg.meta().unwrap()["color"], "blue");
+}
+
+#[test]
+fn test_deserialize_invalid() {
+    use crate::aggregation::Aggregation;
+
+    for s in [
+        r#"{"terms":{"field":"x","typo":3}}"#,
+        r#"{"terms":{"field":"x"},"typo":{}}"#,
+        r#"{"terms":{"field":"x"},"avg":{"field":"y"}}"#,
+        r#"{"avg":{"field":"y"},"aggs":{"max_y":{"max":{"field":"y"}}}}"#,
+        r#"{"meta":{"color":"blue"}}"#,
+    ] {
+        assert!(serde_json::from_str::<Aggregation>(s).is_err());
+    }
+}
+
+#[test]
+#[should_panic(expected = "can't add the named filter `errors` to anonymous filters")]
+fn test_filters_anonymous_named_filter() {
+    use crate::{
+        aggregation::filter::Filters,
+        clause,
+        query::QueryValue,
+    };
+
+    let _ = Filters::anonymous([clause!(Match, "body", QueryValue::from("warning"))])
+        .filter("errors", clause!(Match, "body", QueryValue::from("error")));
+}
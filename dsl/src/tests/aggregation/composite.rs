@@ -0,0 +1,125 @@
+#[test]
+fn test_composite() {
+    use crate::{
+        aggregation::{
+            composite::{
+                Composite,
+                CompositeSource,
+                DateHistogramSource,
+                GeotileGridSource,
+                HistogramSource,
+                TermsSource,
+            },
+            histogram::CalendarInterval,
+            metric::Avg,
+            Aggregation,
+        },
+        sort::Order,
+        types::time::{TimeUnit, TimeValue},
+    };
+
+    for (agg, s) in [
+        (
+            Aggregation::Composite(
+                Composite::new()
+                    .source(
+                        "date",
+                        CompositeSource::DateHistogram(
+                            DateHistogramSource::calendar("timestamp", CalendarInterval::Day).format("yyyy-MM-dd")
+                        )
+                    )
+                    .source("product", CompositeSource::Terms(TermsSource::new("product").order(Order::Desc).missing_bucket(true)))
+                    .size(2)
+            ),
+            concat!(
+                r#"{"composite":{"sources":[{"date":{"date_histogram":{"field":"timestamp","calendar_interval":"day","format":"yyyy-MM-dd"}}},"#,
+                r#"{"product":{"terms":{"field":"product","order":"desc","missing_bucket":true}}}],"size":2}}"#,
+            ),
+        ),
+        (
+            Aggregation::Composite(
+                Composite::new()
+                    .source("histo", CompositeSource::Histogram(HistogramSource::new("price", 5)))
+                    .source(
+                        "hourly",
+                        CompositeSource::DateHistogram(
+                            DateHistogramSource::fixed("timestamp", TimeValue::new(1, TimeUnit::Hours)).time_zone("+01:00")
+                        )
+                    )
+                    .source("tile", CompositeSource::GeotileGrid(GeotileGridSource::new("location").precision(8)))
+                    .agg("avg_price", Aggregation::Avg(Avg::new().field("price")))
+            ),
+            concat!(
+                r#"{"composite":{"sources":[{"histo":{"histogram":{"field":"price","interval":5}}},"#,
+                r#"{"hourly":{"date_histogram":{"field":"timestamp","fixed_interval":"1h","time_zone":"+01:00"}}},"#,
+                r#"{"tile":{"geotile_grid":{"field":"location","precision":8}}}]},"#,
+                r#""aggs":{"avg_price":{"avg":{"field":"price"}}}}"#,
+            ),
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+
+        let agg: Aggregation = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+    }
+}
+
+#[test]
+fn test_next_composite_page() {
+    use serde_json::json;
+    use crate::{
+        aggregation::{
+            composite::{Composite, CompositeSource, TermsSource},
+            metric::Max,
+            Aggregation,
+        },
+        search::Search,
+    };
+
+    let search = Search::new()
+        .agg(
+            "pairs",
+            Aggregation::Composite(
+                Composite::new()
+                    .source("user", CompositeSource::Terms(TermsSource::new("user.id")))
+                    .source("host", CompositeSource::Terms(TermsSource::new("host.name")))
+                    .size(2)
+            )
+        )
+        .agg("max_bytes", Aggregation::Max(Max::new().field("bytes")));
+
+    let response = json!({
+        "aggregations": {
+            "pairs": {
+                "after_key": {"user": "kimchy", "host": "web-02"},
+                "buckets": [
+                    {"key": {"user": "kimchy", "host": "web-01"}, "doc_count": 3},
+                    {"key": {"user": "kimchy", "host": "web-02"}, "doc_count": 1}
+                ]
+            },
+            "max_bytes": {"value": 512.0}
+        }
+    });
+
+    let next = search.next_composite_page("pairs", &response).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&next).unwrap(),
+        concat!(
+            r#"{"aggs":{"pairs":{"composite":{"sources":[{"user":{"terms":{"field":"user.id"}}},"#,
+            r#"{"host":{"terms":{"field":"host.name"}}}],"size":2,"after":{"host":"web-02","user":"kimchy"}}},"#,
+            r#""max_bytes":{"max":{"field":"bytes"}}}}"#,
+        )
+    );
+
+    let next = next
+        .next_composite_page("pairs", &json!({"aggregations": {"pairs": {"after_key": {"user": "zoe", "host": "db-01"}}}}))
+        .unwrap();
+
+    assert_eq!(next.build()["aggs"]["pairs"]["composite"]["after"], json!({"user": "zoe", "host": "db-01"}));
+
+    assert!(next.next_composite_page("pairs", &json!({"aggregations": {"pairs": {"buckets": []}}})).is_none());
+    assert!(next.next_composite_page("max_bytes", &json!({"aggregations": {"max_bytes": {"after_key": {}}}})).is_none());
+    assert!(next.next_composite_page("missing", &response).is_none());
+}
@@ -0,0 +1,109 @@
+#[test]
+fn test_nested() {
+    use crate::{
+        aggregation::{
+            metric::Min,
+            nested::{Nested, ReverseNested},
+            terms::Terms,
+            Aggregation,
+        },
+        search::Search,
+    };
+
+    let search = Search::new().agg(
+        "resellers",
+        Aggregation::Nested(
+            Nested::new("resellers")
+                .agg("min_price", Aggregation::Min(Min::new().field("resellers.price")))
+                .agg(
+                    "names",
+                    Aggregation::Terms(
+                        Terms::new()
+                            .field("resellers.reseller")
+                            .agg(
+                                "products",
+                                Aggregation::ReverseNested(
+                                    ReverseNested::new().agg("top_names", Aggregation::Terms(Terms::new().field("name")))
+                                )
+                            )
+                            .agg("comments", Aggregation::ReverseNested(ReverseNested::new().path("comments")))
+                    )
+                )
+        )
+    );
+
+    let s = serde_json::to_string(&search).unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"aggs":{"resellers":{"nested":{"path":"resellers"},"aggs":{"#,
+            r#""min_price":{"min":{"field":"resellers.price"}},"#,
+            r#""names":{"terms":{"field":"resellers.reseller"},"aggs":{"#,
+            r#""products":{"reverse_nested":{},"aggs":{"top_names":{"terms":{"field":"name"}}}},"#,
+            r#""comments":{"reverse_nested":{"path":"comments"}}}}}}}}"#,
+        )
+    );
+
+    let search: Search = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&search).unwrap(), s);
+}
+
+#[test]
+fn test_join_and_global() {
+    use crate::{
+        aggregation::{
+            global::Global,
+            join::{Children, Parent},
+            metric::Avg,
+            terms::Terms,
+            Aggregation,
+        },
+        clause,
+        search::Search,
+    };
+
+    for (agg, s) in [
+        (
+            Aggregation::Children(
+                Children::new("answer").agg("top_names", Aggregation::Terms(Terms::new().field("owner.display_name.keyword")))
+            ),
+            r#"{"children":{"type":"answer"},"aggs":{"top_names":{"terms":{"field":"owner.display_name.keyword"}}}}"#,
+        ),
+        (
+            Aggregation::Parent(Parent::new("answer").agg("tags", Aggregation::Terms(Terms::new().field("tags.keyword")))),
+            r#"{"parent":{"type":"answer"},"aggs":{"tags":{"terms":{"field":"tags.keyword"}}}}"#,
+        ),
+        (Aggregation::Global(Global::new()), r#"{"global":{}}"#),
+    ] {
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+
+        let agg: Aggregation = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+    }
+
+    let search = Search::new()
+        .query(clause!(Term, "type", "t-shirt"))
+        .agg(
+            "all_products",
+            Aggregation::Global(Global::new().agg("avg_price", Aggregation::Avg(Avg::new().field("price"))))
+        )
+        .agg("t_shirts", Aggregation::Avg(Avg::new().field("price")));
+
+    let s = serde_json::to_string(&search).unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"query":{"term":{"type":{"value":"t-shirt"}}},"aggs":{"#,
+            r#""all_products":{"global":{},"aggs":{"avg_price":{"avg":{"field":"price"}}}},"#,
+            r#""t_shirts":{"avg":{"field":"price"}}}}"#,
+        )
+    );
+
+    let search: Search = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&search).unwrap(), s);
+}
@@ -0,0 +1,161 @@
+#[test]
+fn test_metric() {
+    use crate::{
+        aggregation::{
+            cardinality::Cardinality,
+            extended_stats::ExtendedStats,
+            median_absolute_deviation::MedianAbsoluteDeviation,
+            metric::{Avg, Max, Min, Stats, Sum, ValueCount},
+            Aggregation,
+        },
+        script::Script,
+    };
+
+    for (agg, s) in [
+        (Aggregation::Avg(Avg::new().field("grade")), r#"{"avg":{"field":"grade"}}"#),
+        (Aggregation::Max(Max::new().field("price").missing(0)), r#"{"max":{"field":"price","missing":0}}"#),
+        (
+            Aggregation::Min(Min::new().script(Script::new("doc.price.value * params.rate").param("rate", 1.2))),
+            r#"{"min":{"script":{"source":"doc.price.value * params.rate","params":{"rate":1.2}}}}"#,
+        ),
+        (Aggregation::Stats(Stats::new().field("grade")), r#"{"stats":{"field":"grade"}}"#),
+        (Aggregation::Sum(Sum::new().field("price")), r#"{"sum":{"field":"price"}}"#),
+        (Aggregation::ValueCount(ValueCount::new().field("type")), r#"{"value_count":{"field":"type"}}"#),
+        (
+            Aggregation::ExtendedStats(ExtendedStats::new().field("grade").sigma(3.0)),
+            r#"{"extended_stats":{"field":"grade","sigma":3.0}}"#,
+        ),
+        (
+            Aggregation::Cardinality(Cardinality::new().field("type").missing("N/A").precision_threshold(100)),
+            r#"{"cardinality":{"field":"type","missing":"N/A","precision_threshold":100}}"#,
+        ),
+        (
+            Aggregation::MedianAbsoluteDeviation(MedianAbsoluteDeviation::new().field("rating").compression(100.0)),
+            r#"{"median_absolute_deviation":{"field":"rating","compression":100.0}}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+
+        let agg: Aggregation = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+    }
+}
+
+#[test]
+fn test_percentiles() {
+    use crate::aggregation::{
+        percentiles::{PercentileRanks, Percentiles, PercentilesMethod},
+        Aggregation,
+    };
+
+    for (agg, s) in [
+        (
+            Aggregation::Percentiles(Percentiles::new().field("load_time").percents([95.0, 99.0]).keyed(false)),
+            r#"{"percentiles":{"field":"load_time","percents":[95.0,99.0],"keyed":false}}"#,
+        ),
+        (
+            Aggregation::Percentiles(
+                Percentiles::new()
+                    .field("load_time")
+                    .method(PercentilesMethod::TDigest { compression: Some(200.0) })
+            ),
+            r#"{"percentiles":{"field":"load_time","tdigest":{"compression":200.0}}}"#,
+        ),
+        (
+            Aggregation::PercentileRanks(
+                PercentileRanks::new([500.0, 600.0])
+                    .field("load_time")
+                    .method(PercentilesMethod::Hdr { number_of_significant_value_digits: 3 })
+            ),
+            r#"{"percentile_ranks":{"field":"load_time","values":[500.0,600.0],"hdr":{"number_of_significant_value_digits":3}}}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+
+        let agg: Aggregation = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+    }
+
+    for s in [
+        r#"{"percentiles":{"field":"load_time","hdr":{"digits":3}}}"#,
+        r#"{"percentiles":{"field":"load_time","tdigest":{"compression":"high"}}}"#,
+        r#"{"percentiles":{"field":"load_time","tdigest":{},"hdr":{"number_of_significant_value_digits":3}}}"#,
+        r#"{"percentile_ranks":{"field":"load_time","values":[500.0],"typo":3}}"#,
+    ] {
+        assert!(serde_json::from_str::<Aggregation>(s).is_err());
+    }
+}
+
+#[test]
+fn test_weighted_avg_and_top_hits() {
+    use crate::{
+        aggregation::{
+            top_hits::TopHits,
+            values_source::ValuesSource,
+            weighted_avg::WeightedAvg,
+            Aggregation,
+        },
+        sort::{Order, Sort},
+        sort_clause,
+    };
+
+    for (agg, s) in [
+        (
+            Aggregation::WeightedAvg(
+                WeightedAvg::new(ValuesSource::new().field("grade"), ValuesSource::new().field("weight").missing(3))
+            ),
+            r#"{"weighted_avg":{"value":{"field":"grade"},"weight":{"field":"weight","missing":3}}}"#,
+        ),
+        (
+            Aggregation::TopHits(
+                TopHits::new()
+                    .size(1)
+                    .sort(Sort::new().sort(sort_clause!("date", order = Order::Desc)))
+                    .source_includes(["date", "price"])
+            ),
+            r#"{"top_hits":{"size":1,"sort":[{"date":{"order":"desc"}}],"_source":["date","price"]}}"#,
+        ),
+        (Aggregation::TopHits(TopHits::new().source(false)), r#"{"top_hits":{"_source":false}}"#),
+    ] {
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+
+        let agg: Aggregation = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+    }
+}
+
+#[test]
+fn test_search_aggs() {
+    use crate::{
+        aggregation::{
+            metric::{Avg, Max},
+            Aggregation,
+        },
+        search::Search,
+    };
+
+    let search = Search::new()
+        .agg("avg_grade", Aggregation::Avg(Avg::new().field("grade")))
+        .agg("max_grade", Aggregation::Max(Max::new().field("grade")))
+        .agg("avg_grade", Aggregation::Avg(Avg::new().field("grade").missing(10)));
+
+    let s = serde_json::to_string(&search).unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"aggs":{"avg_grade":{"avg":{"field":"grade","missing":10}},"max_grade":{"max":{"field":"grade"}}}}"#
+    );
+
+    let search: Search = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&search).unwrap(), s);
+
+    let search: Search = serde_json::from_str(r#"{"aggregations":{"max_grade":{"max":{"field":"grade"}}}}"#).unwrap();
+
+    assert_eq!(serde_json::to_string(&search).unwrap(), r#"{"aggs":{"max_grade":{"max":{"field":"grade"}}}}"#);
+
+    assert!(serde_json::from_str::<Aggregation>(r#"{"avg":{"field":"grade"},"aggs":{}}"#).is_err());
+}
@@ -0,0 +1,5 @@
+mod bucket;
+mod composite;
+mod join;
+mod metric;
+mod pipeline;
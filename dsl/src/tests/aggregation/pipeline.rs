@@ -0,0 +1,183 @@
+#[test]
+fn test_buckets_path() {
+    use crate::aggregation::buckets_path::BucketsPath;
+
+    for (path, s) in [
+        (BucketsPath::new("sales"), "sales"),
+        (BucketsPath::new("_count"), "_count"),
+        (BucketsPath::new("sales_per_month").agg("sales"), "sales_per_month>sales"),
+        (BucketsPath::new("sales_per_month").agg("stats").metric("avg"), "sales_per_month>stats.avg"),
+        (BucketsPath::new("sale_type['hat']").agg("sales"), "sale_type['hat']>sales"),
+        (BucketsPath::new("load_time").metric("99"), "load_time.99"),
+        (BucketsPath::new("load_time[99.9]"), "load_time[99.9]"),
+    ] {
+        assert_eq!(path.to_string(), s);
+        assert_eq!(s.parse::<BucketsPath>().unwrap(), path);
+        assert_eq!(serde_json::to_string(&path).unwrap(), format!("\"{}\"", s));
+    }
+
+    for s in ["", "sales>", ">sales", "stats."] {
+        assert!(s.parse::<BucketsPath>().is_err());
+    }
+}
+
+#[test]
+fn test_pipeline() {
+    use crate::{
+        aggregation::{
+            buckets_path::BucketsPath,
+            pipeline::{
+                AvgBucket,
+                BucketScript,
+                BucketSelector,
+                BucketSort,
+                CumulativeSum,
+                Derivative,
+                GapPolicy,
+                MaxBucket,
+                MinBucket,
+                MovingFn,
+                SerialDiff,
+                StatsBucket,
+                SumBucket,
+            },
+            Aggregation,
+        },
+        script::Script,
+        sort::{Order, Sort},
+        sort_clause,
+    };
+
+    for (agg, s) in [
+        (
+            Aggregation::AvgBucket(AvgBucket::new(BucketsPath::new("sales_per_month").agg("sales")).gap_policy(GapPolicy::InsertZeros)),
+            r#"{"avg_bucket":{"buckets_path":"sales_per_month>sales","gap_policy":"insert_zeros"}}"#,
+        ),
+        (
+            Aggregation::MaxBucket(MaxBucket::new(BucketsPath::new("sales_per_month").agg("sales"))),
+            r#"{"max_bucket":{"buckets_path":"sales_per_month>sales"}}"#,
+        ),
+        (
+            Aggregation::MinBucket(MinBucket::new(BucketsPath::new("sales_per_month").agg("sales"))),
+            r#"{"min_bucket":{"buckets_path":"sales_per_month>sales"}}"#,
+        ),
+        (
+            Aggregation::SumBucket(SumBucket::new(BucketsPath::new("sales_per_month").agg("sales")).format("0.00")),
+            r#"{"sum_bucket":{"buckets_path":"sales_per_month>sales","format":"0.00"}}"#,
+        ),
+        (
+            Aggregation::StatsBucket(StatsBucket::new(BucketsPath::new("sales_per_month").agg("_bucket_count"))),
+            r#"{"stats_bucket":{"buckets_path":"sales_per_month>_bucket_count"}}"#,
+        ),
+        (
+            Aggregation::Derivative(Derivative::new(BucketsPath::new("sales")).unit("day")),
+            r#"{"derivative":{"buckets_path":"sales","unit":"day"}}"#,
+        ),
+        (
+            Aggregation::CumulativeSum(CumulativeSum::new(BucketsPath::new("_count"))),
+            r#"{"cumulative_sum":{"buckets_path":"_count"}}"#,
+        ),
+        (
+            Aggregation::MovingFn(
+                MovingFn::new(BucketsPath::new("the_sum"), 10, Script::new("MovingFunctions.unweightedAvg(values)")).shift(1)
+            ),
+            r#"{"moving_fn":{"buckets_path":"the_sum","window":10,"script":{"source":"MovingFunctions.unweightedAvg(values)"},"shift":1}}"#,
+        ),
+        (
+            Aggregation::BucketScript(
+                BucketScript::new(Script::new("params.tShirtSales / params.totalSales * 100"))
+                    .path("totalSales", BucketsPath::new("total_sales"))
+                    .path("tShirtSales", BucketsPath::new("t-shirts").agg("sales"))
+            ),
+            concat!(
+                r#"{"bucket_script":{"buckets_path":{"tShirtSales":"t-shirts>sales","totalSales":"total_sales"},"#,
+                r#""script":{"source":"params.tShirtSales / params.totalSales * 100"}}}"#,
+            ),
+        ),
+        (
+            Aggregation::BucketSelector(
+                BucketSelector::new(Script::new("params.totalSales > 200")).path("totalSales", BucketsPath::new("total_sales"))
+            ),
+            r#"{"bucket_selector":{"buckets_path":{"totalSales":"total_sales"},"script":{"source":"params.totalSales > 200"}}}"#,
+        ),
+        (
+            Aggregation::BucketSort(
+                BucketSort::new().sort(Sort::new().sort(sort_clause!("total_sales", order = Order::Desc))).size(3)
+            ),
+            r#"{"bucket_sort":{"sort":[{"total_sales":{"order":"desc"}}],"size":3}}"#,
+        ),
+        (
+            Aggregation::SerialDiff(SerialDiff::new(BucketsPath::new("the_sum")).lag(30)),
+            r#"{"serial_diff":{"buckets_path":"the_sum","lag":30}}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+
+        let agg: Aggregation = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&agg).unwrap(), s);
+    }
+
+    assert!(serde_json::from_str::<Aggregation>(r#"{"derivative":{"buckets_path":"sales"},"aggs":{}}"#).is_err());
+}
+
+#[test]
+fn test_validate() {
+    use crate::{
+        aggregation::{
+            buckets_path::BucketsPath,
+            histogram::{CalendarInterval, DateHistogram},
+            metric::{Stats, Sum},
+            pipeline::{AvgBucket, BucketScript, CumulativeSum, Derivative},
+            Aggregation,
+        },
+        script::Script,
+        search::Search,
+    };
+
+    fn build_search(sibling_path: BucketsPath, parent_path: BucketsPath) -> Search {
+        Search::new()
+            .agg(
+                "sales_per_month",
+                Aggregation::DateHistogram(
+                    DateHistogram::calendar(CalendarInterval::Month)
+                        .field("date")
+                        .agg("sales", Aggregation::Sum(Sum::new().field("price")))
+                        .agg("price_stats", Aggregation::Stats(Stats::new().field("price")))
+                        .agg("sales_deriv", Aggregation::Derivative(Derivative::new(parent_path)))
+                        .agg("count_sum", Aggregation::CumulativeSum(CumulativeSum::new(BucketsPath::new("_count"))))
+                )
+            )
+            .agg("avg_monthly_sales", Aggregation::AvgBucket(AvgBucket::new(sibling_path)))
+    }
+
+    assert!(Search::new().validate().is_ok());
+
+    for (sibling_path, parent_path) in [
+        (BucketsPath::new("sales_per_month").agg("sales"), BucketsPath::new("sales")),
+        (BucketsPath::new("sales_per_month").agg("price_stats").metric("avg"), BucketsPath::new("price_stats").metric("max")),
+        (BucketsPath::new("sales_per_month").agg("_count"), BucketsPath::new("_count")),
+        (BucketsPath::new("sales_per_month").agg("_bucket_count"), BucketsPath::new("sales_deriv")),
+    ] {
+        assert!(build_search(sibling_path, parent_path).validate().is_ok());
+    }
+
+    assert_eq!(
+        build_search(BucketsPath::new("sales_per_month").agg("sale"), BucketsPath::new("sales")).validate(),
+        Err("the buckets_path `sales_per_month>sale` of `avg_monthly_sales` refers to the missing aggregation `sale`".to_owned())
+    );
+    assert_eq!(
+        build_search(BucketsPath::new("sales_per_month").agg("sales"), BucketsPath::new("sales_per_month").agg("sales")).validate(),
+        Err("the buckets_path `sales_per_month>sales` of `sales_deriv` refers to the missing aggregation `sales_per_month`".to_owned())
+    );
+    assert!(build_search(BucketsPath::new("sales"), BucketsPath::new("sales")).validate().is_err());
+    assert!(build_search(BucketsPath::new("sales_per_month").agg("sales").agg("value"), BucketsPath::new("sales")).validate().is_err());
+
+    let agg = Aggregation::BucketScript(
+        BucketScript::new(Script::new("params.a / params.b"))
+            .path("a", BucketsPath::new("sales"))
+            .path("b", BucketsPath::new("missing"))
+    );
+
+    assert!(Search::new().agg("ratio", agg).validate().is_err());
+}
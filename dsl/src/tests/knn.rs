@@ -0,0 +1,92 @@
+#[test]
+fn test_knn() {
+    use crate::{
+        clause,
+        knn::Knn,
+        query::QueryValue,
+        search::Search,
+    };
+
+    let search = Search::new()
+        .query(clause!(Match, "title", QueryValue::from("mountain lake")))
+        .knn(
+            Knn::new("image-vector", [54.0, 10.0, -2.0], 5, 50)
+                .filter(clause!(Term, "file-type", "png"))
+                .similarity(0.8)
+                .boost(0.9)
+        );
+
+    let s = serde_json::to_string(&search).unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"query":{"match":{"title":{"query":"mountain lake"}}},"#,
+            r#""knn":{"field":"image-vector","query_vector":[54.0,10.0,-2.0],"k":5,"num_candidates":50,"#,
+            r#""filter":[{"term":{"file-type":{"value":"png"}}}],"similarity":0.8,"boost":0.9}}"#,
+        )
+    );
+
+    let search: Search = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&search).unwrap(), s);
+
+    let search = Search::new()
+        .knn(Knn::new("image-vector", [54.0, 10.0, -2.0], 5, 50))
+        .knn(Knn::new("title-vector", [1.0, 0.5], 10, 100));
+
+    let s = serde_json::to_string(&search).unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"knn":[{"field":"image-vector","query_vector":[54.0,10.0,-2.0],"k":5,"num_candidates":50},"#,
+            r#"{"field":"title-vector","query_vector":[1.0,0.5],"k":10,"num_candidates":100}]}"#,
+        )
+    );
+
+    let search: Search = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&search).unwrap(), s);
+}
+
+#[test]
+fn test_vector_functions() {
+    use crate::query::{
+        match_all::MatchAll,
+        script_score::ScriptScore,
+        Query,
+    };
+
+    for (script_score, source) in [
+        (
+            ScriptScore::cosine_similarity(Query::MatchAll(MatchAll::new()), "my_dense_vector", [4.0, 3.4, -0.2]),
+            "cosineSimilarity(params.query_vector, params.field) + 1.0",
+        ),
+        (
+            ScriptScore::dot_product(Query::MatchAll(MatchAll::new()), "my_dense_vector", [4.0, 3.4, -0.2]),
+            "double value = dotProduct(params.query_vector, params.field); return sigmoid(1, Math.E, -value);",
+        ),
+        (
+            ScriptScore::l2_norm(Query::MatchAll(MatchAll::new()), "my_dense_vector", [4.0, 3.4, -0.2]),
+            "1 / (1 + l2norm(params.query_vector, params.field))",
+        ),
+    ] {
+        assert_eq!(
+            serde_json::to_string(&Query::ScriptScore(script_score)).unwrap(),
+            format!(
+                concat!(
+                    r#"{{"script_score":{{"query":{{"match_all":{{}}}},"script":{{"source":"{}","#,
+                    r#""params":{{"field":"my_dense_vector","query_vector":[4.0,3.4,-0.2]}}}}}}}}"#,
+                ),
+                source
+            )
+        );
+    }
+
+    let q = Query::ScriptScore(ScriptScore::l2_norm(Query::MatchAll(MatchAll::new()), r"it's\a_vector", [1.0]));
+    let script = &serde_json::to_value(&q).unwrap()["script_score"]["script"];
+
+    assert_eq!(script["source"], "1 / (1 + l2norm(params.query_vector, params.field))");
+    assert_eq!(script["params"]["field"], r"it's\a_vector");
+}
@@ -1,2 +1,6 @@
+mod aggregation;
+mod knn;
 mod query;
+mod search;
+mod send_sync;
 mod sort;
@@ -0,0 +1,101 @@
+#[test]
+fn test_serialize() {
+    use crate::clause;
+    use crate::query::{
+        bool::Bool,
+        nested::Nested,
+        wildcard::Wildcard,
+        Query,
+        QueryValue,
+    };
+
+    let s = serde_json::to_string(
+        &Query::Nested(
+            Nested::new(
+                "event",
+                Query::Bool(
+                    Bool::new()
+                        .must(
+                            Query::Bool(
+                                Bool::new()
+                                    .should(
+                                        clause!(
+                                            Match,
+                                            "event.action",
+                                            QueryValue::Text("logged-in".to_owned())
+                                        )
+                                    )
+                                    .should(
+                                        Query::Wildcard(Wildcard::new("event.user", "ki*y", "ki*y"))
+                                    )
+                            )
+                        )
+                        .must_not(clause!(Exists, "event.error"))
+                )
+            )
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"nested":{"path":"event","query":{"bool":{"must":[{"bool":{"should":["#,
+            r#"{"match":{"event.action":{"query":"logged-in"}}},"#,
+            r#"{"wildcard":{"event.user":{"value":"ki*y","wildcard":"ki*y"}}}]}}],"#,
+            r#""must_not":[{"exists":{"field":"event.error"}}]}}}}"#,
+        )
+    );
+}
+
+#[test]
+fn test_minimum_should_match() {
+    use serde_json::json;
+    use crate::clause;
+    use crate::query::{bool::Bool, Query};
+
+    let q = Query::Bool(
+        Bool::new()
+            .should(clause!(Term, "tags", "env1"))
+            .should(clause!(Term, "tags", "deployed"))
+            .minimum_should_match(1)
+            .boost(2.0)
+    );
+
+    let s = serde_json::to_string(&q).unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"bool":{"should":[{"term":{"tags":{"value":"env1"}}},{"term":{"tags":{"value":"deployed"}}}],"#,
+            r#""minimum_should_match":1,"boost":2.0}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+
+    let q: Query = serde_json::from_value(json!({
+        "bool": {"should": [{"term": {"tags": "env1"}}], "minimum_should_match": "75%"}
+    }))
+    .unwrap();
+
+    assert_eq!(serde_json::to_value(&q).unwrap()["bool"]["minimum_should_match"], json!("75%"));
+}
+
+#[test]
+fn test_deserialize_unknown_field() {
+    use serde_json::json;
+    use crate::query::Query;
+
+    assert!(
+        serde_json::from_value::<Query>(json!({
+            "bool": {"should": [{"term": {"tags": "env1"}}], "minimum_should_macth": 1}
+        }))
+        .is_err()
+    );
+
+    assert!(serde_json::from_value::<Query>(json!({"range": {"age": {"gte": 1, "typo": 3}}})).is_err());
+    assert!(serde_json::from_value::<Query>(json!({"match": {"title": {"query": "rust", "typo": 3}}})).is_err());
+}
@@ -0,0 +1,87 @@
+#[test]
+fn test_constant_score() {
+    use crate::{clause, query::Query};
+
+    let s = serde_json::to_string(
+        &clause!(ConstantScore, clause!(Term, "user.id", "kimchy"), boost = 1.2)
+    )
+    .unwrap();
+
+    assert_eq!(s, r#"{"constant_score":{"filter":{"term":{"user.id":{"value":"kimchy"}}},"boost":1.2}}"#);
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
+
+#[test]
+fn test_dis_max() {
+    use crate::{
+        clause,
+        query::{
+            bool::Bool,
+            dis_max::DisMax,
+            Query,
+            QueryValue,
+        },
+    };
+
+    let s = serde_json::to_string(
+        &Query::DisMax(
+            DisMax::new(clause!(Term, "title", "Quick pets"))
+                .query(
+                    Query::Bool(
+                        Bool::new()
+                            .should(clause!(Match, "body", QueryValue::from("Quick pets")))
+                    )
+                )
+                .tie_breaker(0.7)
+                .boost(1.5)
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"dis_max":{"queries":[{"term":{"title":{"value":"Quick pets"}}},"#,
+            r#"{"bool":{"should":[{"match":{"body":{"query":"Quick pets"}}}]}}],"tie_breaker":0.7,"boost":1.5}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
+
+#[test]
+fn test_boosting() {
+    use crate::{
+        clause,
+        query::{boosting::Boosting, Query, QueryValue},
+    };
+
+    let s = serde_json::to_string(
+        &Query::Boosting(
+            Boosting::new(
+                clause!(Term, "text", "apple"),
+                clause!(Match, "text", QueryValue::from("pie tart fruit crumble tree")),
+                0.5
+            )
+            .boost(2.0)
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"boosting":{"positive":{"term":{"text":{"value":"apple"}}},"#,
+            r#""negative":{"match":{"text":{"query":"pie tart fruit crumble tree"}}},"negative_boost":0.5,"boost":2.0}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
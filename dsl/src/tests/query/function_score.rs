@@ -0,0 +1,148 @@
+#[test]
+fn test_function_score() {
+    use crate::{
+        clause,
+        query::{
+            function_score::{
+                BoostMode,
+                Decay,
+                FieldValueFactor,
+                Function,
+                FunctionScore,
+                Modifier,
+                MultiValueMode,
+                RandomScore,
+                ScoreMode,
+            },
+            Query,
+            QueryValue,
+        },
+        script::Script,
+    };
+
+    let s = serde_json::to_string(
+        &Query::FunctionScore(
+            FunctionScore::new()
+                .query(clause!(Match, "title", QueryValue::from("phone")))
+                .function(
+                    Function::field_value_factor(
+                        FieldValueFactor::new("likes")
+                            .factor(1.2)
+                            .modifier(Modifier::Log1p)
+                            .missing(1)
+                    )
+                )
+                .function(
+                    Function::random_score(RandomScore::new().seed(10).field("_seq_no"))
+                        .filter(clause!(Term, "brand", "acme"))
+                        .weight(23.0)
+                )
+                .function(Function::script_score(Script::new("Math.log(2 + doc['likes'].value)")))
+                .function(Function::new().filter(clause!(Term, "on_sale", "true")).weight(42.0))
+                .score_mode(ScoreMode::Max)
+                .boost_mode(BoostMode::Replace)
+                .max_boost(42.0)
+                .min_score(4.0)
+                .boost(5.0)
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"function_score":{"query":{"match":{"title":{"query":"phone"}}},"functions":["#,
+            r#"{"field_value_factor":{"field":"likes","factor":1.2,"modifier":"log1p","missing":1}},"#,
+            r#"{"filter":{"term":{"brand":{"value":"acme"}}},"random_score":{"seed":10,"field":"_seq_no"},"weight":23.0},"#,
+            r#"{"script_score":{"script":{"source":"Math.log(2 + doc['likes'].value)"}}},"#,
+            r#"{"filter":{"term":{"on_sale":{"value":"true"}}},"weight":42.0}],"#,
+            r#""score_mode":"max","boost_mode":"replace","max_boost":42.0,"min_score":4.0,"boost":5.0}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+
+    let s = serde_json::to_string(
+        &Query::FunctionScore(
+            FunctionScore::new()
+                .function(Function::gauss(Decay::new("price", 0, 20).offset(5)))
+                .function(
+                    Function::exp(Decay::new("published_at", "now", "10d").offset("5d").decay(0.5))
+                        .weight(2.0)
+                )
+                .function(
+                    Function::linear(
+                        Decay::new("location", "11, 12", "2km").multi_value_mode(MultiValueMode::Avg)
+                    )
+                )
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"function_score":{"functions":["#,
+            r#"{"gauss":{"price":{"origin":0,"scale":20,"offset":5}}},"#,
+            r#"{"exp":{"published_at":{"origin":"now","scale":"10d","offset":"5d","decay":0.5}},"weight":2.0},"#,
+            r#"{"linear":{"location":{"origin":"11, 12","scale":"2km"},"multi_value_mode":"avg"}}]}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
+
+#[test]
+fn test_deserialize_single_function() {
+    use serde_json::json;
+    use crate::query::Query;
+
+    let q: Query = serde_json::from_value(json!({
+        "function_score": {
+            "query": {"match_all": {}},
+            "random_score": {},
+            "weight": 2.0,
+            "boost_mode": "multiply"
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&q).unwrap(),
+        json!({
+            "function_score": {
+                "query": {"match_all": {}},
+                "functions": [{"random_score": {}, "weight": 2.0}]
+            }
+        })
+    );
+
+    assert!(
+        serde_json::from_value::<Query>(json!({
+            "function_score": {"random_score": {}, "functions": [{"weight": 2.0}]}
+        }))
+        .is_err()
+    );
+}
+
+#[test]
+fn test_deserialize_malformed_function() {
+    use serde_json::json;
+    use crate::query::Query;
+
+    let invalid = [
+        json!({"functions": [{"gauss": {"price": {"origin": 0}}}]}),
+        json!({"functions": [{"field_value_factor": {"factor": 1.2}}]}),
+        json!({"functions": [{"gauss": {"price": {"origin": 0, "scale": 20}}, "exp": {"price": {"origin": 0, "scale": 20}}}]}),
+        json!({"functions": [{"random_score": {}, "wieght": 2.0}]}),
+        json!({"gauss": {"price": {"origin": 0}}}),
+    ];
+
+    for v in invalid {
+        assert!(serde_json::from_value::<Query>(json!({"function_score": v})).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+#[test]
+fn test_serialize() {
+    use crate::clause;
+
+    let s = serde_json::to_string(&clause!(Fuzzy, "user.id", "ki")).unwrap();
+
+    assert_eq!(s, r#"{"fuzzy":{"user.id":{"value":"ki"}}}"#);
+
+    let s = serde_json::to_string(
+        &clause!(
+            Fuzzy,
+            "user.id",
+            "ki",
+            fuzziness = "AUTO",
+            max_expansions = 50,
+            prefix_length = 1,
+            transpositions = false,
+            rewrite = "constant_score"
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"fuzzy":{"user.id":{"value":"ki","fuzziness":"AUTO","prefix_length":1,"transpositions":false,"rewrite":"constant_score"}}}"#
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    use crate::query::Query;
+
+    let q: Query = serde_json::from_str(r#"{"fuzzy":{"user.id":"ki"}}"#).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), r#"{"fuzzy":{"user.id":{"value":"ki"}}}"#);
+}
@@ -0,0 +1,269 @@
+#[test]
+fn test_geo_point() {
+    use crate::types::geo::GeoPoint;
+
+    for (point, s) in [
+        (GeoPoint::lat_lon(41.12, -71.34), r#"{"lat":41.12,"lon":-71.34}"#),
+        (GeoPoint::array(41.12, -71.34), r#"[-71.34,41.12]"#),
+        (GeoPoint::string(41.12, -71.34), r#""41.12,-71.34""#),
+        (GeoPoint::geohash("drm3btev3e86"), r#""drm3btev3e86""#),
+        (GeoPoint::wkt(41.12, -71.34), r#""POINT (-71.34 41.12)""#),
+    ] {
+        assert_eq!(serde_json::to_string(&point).unwrap(), s);
+        assert_eq!(serde_json::from_str::<GeoPoint>(s).unwrap(), point);
+    }
+}
+
+#[test]
+fn test_distance() {
+    use crate::types::geo::{Distance, DistanceUnit};
+
+    assert_eq!(serde_json::to_string(&Distance::new(12, DistanceUnit::Kilometers)).unwrap(), r#""12km""#);
+    assert_eq!(serde_json::to_string(&Distance::new(0.5, DistanceUnit::NauticalMiles)).unwrap(), r#""0.5nmi""#);
+    assert_eq!(serde_json::from_str::<Distance>(r#""200 KM""#).unwrap(), Distance::new(200, DistanceUnit::Kilometers));
+    assert_eq!(serde_json::from_str::<Distance>(r#""12""#).unwrap(), Distance::new(12, DistanceUnit::Meters));
+    assert!(serde_json::from_str::<Distance>(r#""12parsecs""#).is_err());
+}
+
+#[test]
+fn test_geo_distance() {
+    use crate::{
+        query::{
+            geo_distance::{DistanceType, GeoDistance},
+            Query,
+        },
+        types::geo::{Distance, DistanceUnit, GeoPoint, ValidationMethod},
+    };
+
+    let s = serde_json::to_string(
+        &Query::GeoDistance(
+            GeoDistance::new("pin.location", GeoPoint::lat_lon(40.0, -70.0), Distance::new(200, DistanceUnit::Kilometers))
+                .distance_type(DistanceType::Plane)
+                .validation_method(ValidationMethod::IgnoreMalformed)
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"geo_distance":{"pin.location":{"lat":40.0,"lon":-70.0},"distance":"200km","#,
+            r#""distance_type":"plane","validation_method":"IGNORE_MALFORMED"}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
+
+#[test]
+fn test_geo_bounding_box() {
+    use crate::{
+        query::{
+            geo_bounding_box::{BoundingBox, GeoBoundingBox},
+            Query,
+        },
+        types::geo::GeoPoint,
+    };
+
+    for (bounding_box, s) in [
+        (
+            BoundingBox::corners(GeoPoint::lat_lon(40.73, -74.1), GeoPoint::lat_lon(40.01, -71.12)),
+            r#"{"geo_bounding_box":{"pin.location":{"top_left":{"lat":40.73,"lon":-74.1},"bottom_right":{"lat":40.01,"lon":-71.12}}}}"#,
+        ),
+        (
+            BoundingBox::vertices(40.73, -74.1, 40.01, -71.12),
+            r#"{"geo_bounding_box":{"pin.location":{"top":40.73,"left":-74.1,"bottom":40.01,"right":-71.12}}}"#,
+        ),
+        (
+            BoundingBox::wkt("BBOX (-74.1, -71.12, 40.73, 40.01)"),
+            r#"{"geo_bounding_box":{"pin.location":{"wkt":"BBOX (-74.1, -71.12, 40.73, 40.01)"}}}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&Query::GeoBoundingBox(GeoBoundingBox::new("pin.location", bounding_box))).unwrap(), s);
+
+        let q: Query = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&q).unwrap(), s);
+    }
+}
+
+#[test]
+fn test_geo_polygon() {
+    use crate::{
+        query::{geo_polygon::GeoPolygon, Query},
+        types::geo::GeoPoint,
+    };
+
+    let s = serde_json::to_string(
+        &Query::GeoPolygon(
+            GeoPolygon::new(
+                "person.location",
+                [
+                    GeoPoint::lat_lon(40.0, -70.0),
+                    GeoPoint::string(30.0, -80.0),
+                    GeoPoint::geohash("drn5x1g8cu2y"),
+                ]
+            )
+            .ignore_unmapped(true)
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"geo_polygon":{"person.location":{"points":[{"lat":40.0,"lon":-70.0},"30,-80","drn5x1g8cu2y"]},"#,
+            r#""ignore_unmapped":true}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
+
+#[test]
+fn test_geo_shape() {
+    use crate::query::{
+        geo_shape::{GeoShape, IndexedShape, Shape, SpatialRelation},
+        Query,
+    };
+
+    let s = serde_json::to_string(
+        &Query::GeoShape(
+            GeoShape::new(
+                "location",
+                Shape::Envelope {
+                    coordinates: [[13.0, 53.0], [14.0, 52.0]],
+                }
+            )
+            .relation(SpatialRelation::Disjoint)
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"geo_shape":{"location":{"shape":{"type":"envelope","coordinates":[[13.0,53.0],[14.0,52.0]]},"relation":"disjoint"}}}"#
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+
+    let s = serde_json::to_string(
+        &Query::GeoShape(
+            GeoShape::new(
+                "location",
+                Shape::Polygon {
+                    coordinates: vec![vec![[100.0, 0.0], [101.0, 0.0], [101.0, 1.0], [100.0, 0.0]]],
+                }
+            )
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"geo_shape":{"location":{"shape":{"type":"polygon","coordinates":[[[100.0,0.0],[101.0,0.0],[101.0,1.0],[100.0,0.0]]]}}}}"#
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+
+    let s = serde_json::to_string(
+        &Query::GeoShape(
+            GeoShape::indexed("location", IndexedShape::new("shapes", "deu").path("location"))
+                .ignore_unmapped(true)
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"geo_shape":{"location":{"indexed_shape":{"index":"shapes","id":"deu","path":"location"}},"ignore_unmapped":true}}"#
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
+
+#[test]
+fn test_geo_shape_geojson_types() {
+    use crate::query::Query;
+
+    for (geojson, s) in [
+        (
+            r#"{"geo_shape":{"location":{"shape":{"type":"Point","coordinates":[-77.0,38.0]}}}}"#,
+            r#"{"geo_shape":{"location":{"shape":{"type":"point","coordinates":[-77.0,38.0]}}}}"#,
+        ),
+        (
+            r#"{"geo_shape":{"location":{"shape":{"type":"LineString","coordinates":[[-77.0,38.0],[-77.1,38.1]]}}}}"#,
+            r#"{"geo_shape":{"location":{"shape":{"type":"linestring","coordinates":[[-77.0,38.0],[-77.1,38.1]]}}}}"#,
+        ),
+        (
+            r#"{"geo_shape":{"location":{"shape":{"type":"Polygon","coordinates":[[[100.0,0.0],[101.0,0.0],[101.0,1.0],[100.0,0.0]]]}}}}"#,
+            r#"{"geo_shape":{"location":{"shape":{"type":"polygon","coordinates":[[[100.0,0.0],[101.0,0.0],[101.0,1.0],[100.0,0.0]]]}}}}"#,
+        ),
+        (
+            r#"{"geo_shape":{"location":{"shape":{"type":"MultiPoint","coordinates":[[102.0,2.0],[103.0,2.0]]}}}}"#,
+            r#"{"geo_shape":{"location":{"shape":{"type":"multipoint","coordinates":[[102.0,2.0],[103.0,2.0]]}}}}"#,
+        ),
+        (
+            r#"{"geo_shape":{"location":{"shape":{"type":"MultiLineString","coordinates":[[[102.0,2.0],[103.0,2.0]]]}}}}"#,
+            r#"{"geo_shape":{"location":{"shape":{"type":"multilinestring","coordinates":[[[102.0,2.0],[103.0,2.0]]]}}}}"#,
+        ),
+        (
+            r#"{"geo_shape":{"location":{"shape":{"type":"MultiPolygon","coordinates":[[[[102.0,2.0],[103.0,2.0],[102.0,2.0]]]]}}}}"#,
+            r#"{"geo_shape":{"location":{"shape":{"type":"multipolygon","coordinates":[[[[102.0,2.0],[103.0,2.0],[102.0,2.0]]]]}}}}"#,
+        ),
+        (
+            r#"{"geo_shape":{"location":{"shape":{"type":"GeometryCollection","geometries":[{"type":"Point","coordinates":[100.0,0.0]}]}}}}"#,
+            r#"{"geo_shape":{"location":{"shape":{"type":"geometrycollection","geometries":[{"type":"point","coordinates":[100.0,0.0]}]}}}}"#,
+        ),
+        (
+            r#"{"geo_shape":{"location":{"shape":{"type":"Envelope","coordinates":[[13.0,53.0],[14.0,52.0]]}}}}"#,
+            r#"{"geo_shape":{"location":{"shape":{"type":"envelope","coordinates":[[13.0,53.0],[14.0,52.0]]}}}}"#,
+        ),
+    ] {
+        let q: Query = serde_json::from_str(geojson).unwrap();
+
+        assert_eq!(serde_json::to_string(&q).unwrap(), s);
+    }
+}
+
+#[test]
+fn test_geo_decay() {
+    use crate::{
+        query::{
+            function_score::{Decay, Function, FunctionScore},
+            Query,
+        },
+        types::geo::{Distance, DistanceUnit, GeoPoint},
+    };
+
+    let s = serde_json::to_string(
+        &Query::FunctionScore(
+            FunctionScore::new()
+                .function(
+                    Function::gauss(
+                        Decay::new("location", GeoPoint::lat_lon(11.0, 12.0), Distance::new(2, DistanceUnit::Kilometers))
+                    )
+                )
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"function_score":{"functions":[{"gauss":{"location":{"origin":{"lat":11.0,"lon":12.0},"scale":"2km"}}}]}}"#
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
@@ -0,0 +1,16 @@
+#[test]
+fn test_serialize() {
+    use crate::{clause, query::Query};
+
+    let s = serde_json::to_string(&clause!(Ids, ["1", "4", "100"])).unwrap();
+
+    assert_eq!(s, r#"{"ids":{"values":["1","4","100"]}}"#);
+
+    let s = serde_json::to_string(&clause!(Ids, vec![String::from("1")], boost = 3.0)).unwrap();
+
+    assert_eq!(s, r#"{"ids":{"values":["1"],"boost":3.0}}"#);
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
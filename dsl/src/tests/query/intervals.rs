@@ -0,0 +1,136 @@
+#[test]
+fn test_intervals() {
+    use crate::query::{
+        intervals::{
+            AllOfRule,
+            AnyOfRule,
+            Intervals,
+            IntervalsRule,
+            MatchRule,
+        },
+        Query,
+    };
+
+    let s = serde_json::to_string(
+        &Query::Intervals(
+            Intervals::new(
+                "my_text",
+                IntervalsRule::AllOf(
+                    AllOfRule::new()
+                        .interval(IntervalsRule::Match(MatchRule::new("my favorite food").max_gaps(0).ordered(true)))
+                        .interval(
+                            IntervalsRule::AnyOf(
+                                AnyOfRule::new()
+                                    .interval(IntervalsRule::Match(MatchRule::new("hot water")))
+                                    .interval(IntervalsRule::Match(MatchRule::new("cold porridge")))
+                            )
+                        )
+                        .ordered(true)
+                )
+            )
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"intervals":{"my_text":{"all_of":{"intervals":["#,
+            r#"{"match":{"query":"my favorite food","max_gaps":0,"ordered":true}},"#,
+            r#"{"any_of":{"intervals":[{"match":{"query":"hot water"}},{"match":{"query":"cold porridge"}}]}}],"#,
+            r#""ordered":true}}}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
+
+#[test]
+fn test_intervals_rules() {
+    use crate::query::{
+        intervals::{
+            FuzzyRule,
+            Intervals,
+            IntervalsRule,
+            PrefixRule,
+            WildcardRule,
+        },
+        Query,
+    };
+
+    for (rule, s) in [
+        (
+            IntervalsRule::Prefix(PrefixRule::new("out").use_field("my_text.prefixes")),
+            r#"{"intervals":{"my_text":{"prefix":{"prefix":"out","use_field":"my_text.prefixes"}}}}"#,
+        ),
+        (
+            IntervalsRule::Wildcard(WildcardRule::new("o*t").analyzer("standard")),
+            r#"{"intervals":{"my_text":{"wildcard":{"pattern":"o*t","analyzer":"standard"}}}}"#,
+        ),
+        (
+            IntervalsRule::Fuzzy(FuzzyRule::new("porige").fuzziness("AUTO").prefix_length(1).transpositions(false)),
+            r#"{"intervals":{"my_text":{"fuzzy":{"term":"porige","prefix_length":1,"transpositions":false,"fuzziness":"AUTO"}}}}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&Query::Intervals(Intervals::new("my_text", rule))).unwrap(), s);
+
+        let q: Query = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&q).unwrap(), s);
+    }
+}
+
+#[test]
+fn test_intervals_filters() {
+    use crate::{
+        query::{
+            intervals::{
+                Intervals,
+                IntervalsFilter,
+                IntervalsRule,
+                MatchRule,
+            },
+            Query,
+        },
+        script::Script,
+    };
+
+    for (filter, s) in [
+        (
+            IntervalsFilter::not_overlapping(IntervalsRule::Match(MatchRule::new("salty"))),
+            r#"{"not_overlapping":{"match":{"query":"salty"}}}"#,
+        ),
+        (
+            IntervalsFilter::containing(IntervalsRule::Match(MatchRule::new("porridge"))),
+            r#"{"containing":{"match":{"query":"porridge"}}}"#,
+        ),
+        (
+            IntervalsFilter::before(IntervalsRule::Match(MatchRule::new("cold"))),
+            r#"{"before":{"match":{"query":"cold"}}}"#,
+        ),
+        (
+            IntervalsFilter::after(IntervalsRule::Match(MatchRule::new("hot"))),
+            r#"{"after":{"match":{"query":"hot"}}}"#,
+        ),
+        (
+            IntervalsFilter::script(Script::new("interval.start > 10 && interval.gaps == 0")),
+            r#"{"script":{"source":"interval.start > 10 && interval.gaps == 0"}}"#,
+        ),
+    ] {
+        let q = Query::Intervals(
+            Intervals::new("my_text", IntervalsRule::Match(MatchRule::new("hot porridge").filter(filter))).boost(2.0)
+        );
+        let expected = format!(
+            r#"{{"intervals":{{"my_text":{{"match":{{"query":"hot porridge","filter":{}}},"boost":2.0}}}}}}"#,
+            s
+        );
+
+        assert_eq!(serde_json::to_string(&q).unwrap(), expected);
+
+        let q: Query = serde_json::from_str(&expected).unwrap();
+
+        assert_eq!(serde_json::to_string(&q).unwrap(), expected);
+    }
+}
@@ -0,0 +1,109 @@
+#[test]
+fn test_has_child() {
+    use crate::{
+        query::{
+            has_child::HasChild,
+            inner_hits::InnerHits,
+            match_all::MatchAll,
+            nested::ScoreMode,
+            Query,
+        },
+        sort::Sort,
+        sort_clause,
+    };
+
+    let s = serde_json::to_string(
+        &Query::HasChild(
+            HasChild::new("line_item", Query::MatchAll(MatchAll::new()))
+                .score_mode(ScoreMode::Avg)
+                .min_children(2)
+                .max_children(10)
+                .ignore_unmapped(true)
+                .inner_hits(
+                    InnerHits::new()
+                        .name("items")
+                        .from(0)
+                        .size(3)
+                        .sort(Sort::new().sort(sort_clause!("price")))
+                )
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"has_child":{"type":"line_item","query":{"match_all":{}},"score_mode":"avg","#,
+            r#""min_children":2,"max_children":10,"ignore_unmapped":true,"#,
+            r#""inner_hits":{"name":"items","from":0,"size":3,"sort":[{"price":{}}]}}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
+
+#[test]
+fn test_has_parent() {
+    use crate::{
+        clause,
+        query::{has_parent::HasParent, inner_hits::InnerHits, Query},
+    };
+
+    let s = serde_json::to_string(
+        &Query::HasParent(
+            HasParent::new("order", clause!(Term, "status", "shipped"))
+                .score(true)
+                .inner_hits(InnerHits::new())
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"has_parent":{"parent_type":"order","query":{"term":{"status":{"value":"shipped"}}},"score":true,"inner_hits":{}}}"#
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
+
+#[test]
+fn test_parent_id() {
+    use crate::query::{parent_id::ParentId, Query};
+
+    let s = serde_json::to_string(&Query::ParentId(ParentId::new("line_item", "1"))).unwrap();
+
+    assert_eq!(s, r#"{"parent_id":{"type":"line_item","id":"1"}}"#);
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
+
+#[test]
+fn test_nested_inner_hits() {
+    use crate::{
+        clause,
+        query::{inner_hits::InnerHits, nested::Nested, Query},
+    };
+
+    let s = serde_json::to_string(
+        &Query::Nested(
+            Nested::new("comments", clause!(Term, "comments.author", "kimchy"))
+                .inner_hits(InnerHits::new().size(1))
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"nested":{"path":"comments","query":{"term":{"comments.author":{"value":"kimchy"}}},"inner_hits":{"size":1}}}"#
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
@@ -1,2 +1,3 @@
+mod test_deserialize;
 mod test_operator;
 mod test_zero_terms_query;
@@ -0,0 +1,38 @@
+#[test]
+fn test_deserialize() {
+    use crate::query::r#match::Match;
+
+    let m: Match = serde_json::from_str(r#"{"message":"this is a test"}"#).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&m).unwrap(),
+        r#"{"message":{"query":"this is a test"}}"#
+    );
+
+    let m: Match = serde_json::from_str(
+        r#"{"message":{"query":"this is a test","operator":"and","max_expansions":10,"zero_terms_query":"all"}}"#
+    )
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&m).unwrap(),
+        r#"{"message":{"query":"this is a test","max_expansions":10,"operator":"AND","zero_terms_query":"all"}}"#
+    );
+
+    assert!(serde_json::from_str::<Match>(r#"{"message":{"query":"test","operator":"xor"}}"#).is_err());
+}
+
+#[test]
+fn test_boost() {
+    use crate::query::{r#match::Match, Query};
+
+    let s = r#"{"match":{"title":{"query":"rust","boost":2.0}}}"#;
+
+    let q = Query::Match(Match::new("title", "rust".into()).boost(2.0));
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+
+    let q: Query = serde_json::from_str(s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
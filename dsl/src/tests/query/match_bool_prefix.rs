@@ -0,0 +1,39 @@
+#[test]
+fn test_serialize() {
+    use crate::{
+        clause,
+        query::{r#match::Operator, QueryValue},
+    };
+
+    let s = serde_json::to_string(
+        &clause!(
+            MatchBoolPrefix,
+            "message",
+            QueryValue::Text("quick brown f".to_owned()),
+            analyzer = "keyword",
+            fuzziness = "AUTO",
+            operator = Operator::And
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"match_bool_prefix":{"message":{"query":"quick brown f","analyzer":"keyword","fuzziness":"AUTO","operator":"AND"}}}"#
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    use crate::query::Query;
+
+    let q: Query = serde_json::from_str(
+        r#"{"match_bool_prefix":{"message":{"query":"quick brown f","minimum_should_match":"75%","prefix_length":2}}}"#
+    )
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&q).unwrap(),
+        r#"{"match_bool_prefix":{"message":{"query":"quick brown f","prefix_length":2,"minimum_should_match":"75%"}}}"#
+    );
+}
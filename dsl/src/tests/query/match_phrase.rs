@@ -0,0 +1,43 @@
+#[test]
+fn test_serialize() {
+    use crate::{
+        clause,
+        query::{r#match::ZeroTermsQuery, QueryValue},
+    };
+
+    let s = serde_json::to_string(
+        &clause!(MatchPhrase, "message", QueryValue::Text("this is a test".to_owned()))
+    )
+    .unwrap();
+
+    assert_eq!(s, r#"{"match_phrase":{"message":{"query":"this is a test"}}}"#);
+
+    let s = serde_json::to_string(
+        &clause!(
+            MatchPhrase,
+            "message",
+            QueryValue::Text("this is a test".to_owned()),
+            analyzer = "my_analyzer",
+            slop = 2,
+            zero_terms_query = ZeroTermsQuery::All
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"match_phrase":{"message":{"query":"this is a test","analyzer":"my_analyzer","slop":2,"zero_terms_query":"all"}}}"#
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    use crate::query::Query;
+
+    let q: Query = serde_json::from_str(r#"{"match_phrase":{"message":"this is a test"}}"#).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&q).unwrap(),
+        r#"{"match_phrase":{"message":{"query":"this is a test"}}}"#
+    );
+}
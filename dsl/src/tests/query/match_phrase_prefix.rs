@@ -0,0 +1,32 @@
+#[test]
+fn test_serialize() {
+    use crate::{clause, query::QueryValue};
+
+    let s = serde_json::to_string(
+        &clause!(
+            MatchPhrasePrefix,
+            "message",
+            QueryValue::Text("quick brown f".to_owned()),
+            max_expansions = 10,
+            slop = 1
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"match_phrase_prefix":{"message":{"query":"quick brown f","max_expansions":10,"slop":1}}}"#
+    );
+
+    let s = serde_json::to_string(
+        &clause!(
+            MatchPhrasePrefix,
+            "message",
+            QueryValue::Text("quick brown f".to_owned()),
+            max_expansions = 50
+        )
+    )
+    .unwrap();
+
+    assert_eq!(s, r#"{"match_phrase_prefix":{"message":{"query":"quick brown f"}}}"#);
+}
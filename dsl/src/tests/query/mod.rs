@@ -1,5 +1,29 @@
+mod bool;
+mod compound;
+mod function_score;
+mod fuzzy;
+mod geo;
+mod ids;
+mod intervals;
+mod join;
 mod r#match;
 mod match_all;
+mod match_bool_prefix;
 mod match_none;
+mod match_phrase;
+mod match_phrase_prefix;
+mod more_like_this;
+mod multi_match;
+mod percolate;
+mod prefix;
+mod query_string;
 mod range;
+mod regexp;
+mod relevance;
+mod script;
+mod simple_query_string;
+mod span;
+mod term;
+mod terms;
+mod terms_set;
 mod wildcard;
@@ -0,0 +1,60 @@
+#[test]
+fn test_more_like_this() {
+    use crate::query::{
+        more_like_this::{LikeDocument, MoreLikeThis},
+        Query,
+    };
+
+    #[derive(serde::Serialize)]
+    struct Article {
+        name: &'static str,
+    }
+
+    let s = serde_json::to_string(
+        &Query::MoreLikeThis(
+            MoreLikeThis::new("Once upon a time")
+                .field("name.first")
+                .field("name.last")
+                .like(LikeDocument::new("marvel", "1"))
+                .like(
+                    LikeDocument::artificial(serde_json::json!({"name": {"first": "Ben", "last": "Grimm"}}))
+                        .index("marvel")
+                )
+                .like(LikeDocument::try_artificial(&Article { name: "Thing" }).unwrap())
+                .unlike(LikeDocument::new("marvel", "2").routing("a"))
+                .min_term_freq(1)
+                .max_query_terms(12)
+                .min_doc_freq(5)
+                .stop_words(["the", "a"])
+                .minimum_should_match("30%")
+                .boost_terms(1.5)
+                .include(true)
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"more_like_this":{"fields":["name.first","name.last"],"like":["Once upon a time","#,
+            r#"{"_index":"marvel","_id":"1"},"#,
+            r#"{"_index":"marvel","doc":{"name":{"first":"Ben","last":"Grimm"}}},"#,
+            r#"{"doc":{"name":"Thing"}}],"#,
+            r#""unlike":[{"_index":"marvel","_id":"2","routing":"a"}],"max_query_terms":12,"min_term_freq":1,"#,
+            r#""min_doc_freq":5,"stop_words":["the","a"],"minimum_should_match":"30%","boost_terms":1.5,"include":true}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+
+    let q: Query = serde_json::from_str(r#"{"more_like_this":{"fields":["title"],"like":"Once upon a time"}}"#).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&q).unwrap(),
+        r#"{"more_like_this":{"fields":["title"],"like":["Once upon a time"]}}"#
+    );
+
+    assert!(serde_json::from_str::<Query>(r#"{"more_like_this":{"fields":["title"]}}"#).is_err());
+}
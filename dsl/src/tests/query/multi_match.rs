@@ -0,0 +1,57 @@
+#[test]
+fn test_serialize() {
+    use crate::query::{
+        multi_match::{MultiMatch, MultiMatchType},
+        r#match::Operator,
+        QueryValue,
+    };
+
+    let m = MultiMatch::new(QueryValue::Text("brown fox".to_owned()))
+        .field_boost("title", 3.0)
+        .field("body")
+        .field_boost("tags", 1.5);
+
+    assert_eq!(
+        serde_json::to_string(&m).unwrap(),
+        r#"{"query":"brown fox","fields":["title^3","body","tags^1.5"]}"#
+    );
+
+    let m = m
+        .r#type(MultiMatchType::CrossFields)
+        .tie_breaker(0.3)
+        .operator(Operator::And)
+        .minimum_should_match("75%");
+
+    assert_eq!(
+        serde_json::to_string(&m).unwrap(),
+        concat!(
+            r#"{"query":"brown fox","fields":["title^3","body","tags^1.5"],"type":"cross_fields","#,
+            r#""tie_breaker":0.3,"operator":"AND","minimum_should_match":"75%"}"#,
+        )
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    use crate::query::Query;
+
+    let s = r#"{"multi_match":{"query":"quick brown f","fields":["subject^3","message"],"type":"bool_prefix","fuzziness":"AUTO"}}"#;
+
+    let q: Query = serde_json::from_str(s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+
+    assert!(serde_json::from_str::<Query>(r#"{"multi_match":{"query":"fox","fields":["title^x"]}}"#).is_err());
+}
+
+#[test]
+fn test_derive_display_case() {
+    use crate::query::multi_match::MultiMatchType;
+
+    assert_eq!(&MultiMatchType::BestFields.to_string(), "best_fields");
+    assert_eq!(&MultiMatchType::MostFields.to_string(), "most_fields");
+    assert_eq!(&MultiMatchType::CrossFields.to_string(), "cross_fields");
+    assert_eq!(&MultiMatchType::Phrase.to_string(), "phrase");
+    assert_eq!(&MultiMatchType::PhrasePrefix.to_string(), "phrase_prefix");
+    assert_eq!(&MultiMatchType::BoolPrefix.to_string(), "bool_prefix");
+}
@@ -0,0 +1,74 @@
+#[test]
+fn test_percolate() {
+    use crate::query::{percolate::Percolate, Query};
+
+    #[derive(serde::Serialize)]
+    struct Log {
+        message: &'static str,
+    }
+
+    for (percolate, s) in [
+        (
+            Percolate::document("query", serde_json::json!({"message": "A new bonsai tree in the office"})),
+            r#"{"percolate":{"field":"query","document":{"message":"A new bonsai tree in the office"}}}"#,
+        ),
+        (
+            Percolate::try_document("query", &Log { message: "bonsai" }).unwrap().name("log"),
+            r#"{"percolate":{"field":"query","document":{"message":"bonsai"},"name":"log"}}"#,
+        ),
+        (
+            Percolate::documents(
+                "query",
+                [serde_json::json!({"message": "bonsai tree"}), serde_json::json!({"message": "new tree"})]
+            ),
+            r#"{"percolate":{"field":"query","documents":[{"message":"bonsai tree"},{"message":"new tree"}]}}"#,
+        ),
+        (
+            Percolate::indexed("query", "my-index-000001", "2").routing("a").version(1),
+            r#"{"percolate":{"field":"query","index":"my-index-000001","id":"2","routing":"a","version":1}}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&Query::Percolate(percolate)).unwrap(), s);
+
+        let q: Query = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&q).unwrap(), s);
+    }
+}
+
+#[test]
+fn test_percolator_document() {
+    use crate::{
+        clause,
+        query::{bool::Bool, percolate::PercolatorDocument, Query, QueryValue},
+    };
+
+    let doc = PercolatorDocument::new(
+        "query",
+        Query::Bool(
+            Bool::new()
+                .must(clause!(Match, "message", QueryValue::from("bonsai tree")))
+                .filter(clause!(Term, "level", "error"))
+        )
+    )
+    .field("alert", serde_json::json!({"channel": "ops"}))
+    .field("severity", serde_json::json!("warning"))
+    .field("alert", serde_json::json!({"channel": "oncall"}));
+
+    assert_eq!(
+        serde_json::to_string(&doc).unwrap(),
+        concat!(
+            r#"{"query":{"bool":{"must":[{"match":{"message":{"query":"bonsai tree"}}}],"#,
+            r#""filter":[{"term":{"level":{"value":"error"}}}]}},"alert":{"channel":"oncall"},"severity":"warning"}"#,
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "the field `query` holds the query of the percolator document")]
+fn test_percolator_document_query_field() {
+    use crate::query::{match_all::MatchAll, percolate::PercolatorDocument, Query};
+
+    let _ = PercolatorDocument::new("query", Query::MatchAll(MatchAll::new()))
+        .field("query", serde_json::json!("match_all"));
+}
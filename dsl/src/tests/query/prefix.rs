@@ -0,0 +1,27 @@
+#[test]
+fn test_serialize() {
+    use crate::clause;
+
+    let s = serde_json::to_string(&clause!(Prefix, "user.id", "ki")).unwrap();
+
+    assert_eq!(s, r#"{"prefix":{"user.id":{"value":"ki"}}}"#);
+
+    let s = serde_json::to_string(
+        &clause!(Prefix, "user.id", "ki", rewrite = "constant_score", case_insensitive = true, boost = 2.0)
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"prefix":{"user.id":{"value":"ki","rewrite":"constant_score","case_insensitive":true,"boost":2.0}}}"#
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    use crate::query::Query;
+
+    let q: Query = serde_json::from_str(r#"{"prefix":{"user.id":"ki"}}"#).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), r#"{"prefix":{"user.id":{"value":"ki"}}}"#);
+}
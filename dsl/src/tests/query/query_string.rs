@@ -0,0 +1,46 @@
+#[test]
+fn test_serialize() {
+    use crate::{clause, query::r#match::Operator};
+
+    let s = serde_json::to_string(
+        &clause!(QueryString, "(new york city) OR (big apple)")
+    )
+    .unwrap();
+
+    assert_eq!(s, r#"{"query_string":{"query":"(new york city) OR (big apple)"}}"#);
+
+    let s = serde_json::to_string(
+        &clause!(
+            QueryString,
+            "city.* AND \"big apple\"",
+            default_field = "content",
+            default_operator = Operator::And,
+            analyze_wildcard = true,
+            allow_leading_wildcard = false,
+            fuzziness = "AUTO",
+            time_zone = "+01:00",
+            quote_field_suffix = ".exact"
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"query_string":{"query":"city.* AND \"big apple\"","default_field":"content","#,
+            r#""default_operator":"AND","analyze_wildcard":true,"allow_leading_wildcard":false,"#,
+            r#""fuzziness":"AUTO","quote_field_suffix":".exact","time_zone":"+01:00"}}"#,
+        )
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    use crate::query::Query;
+
+    let s = r#"{"query_string":{"query":"this AND that","fields":["title^2","content"],"default_operator":"AND"}}"#;
+
+    let q: Query = serde_json::from_str(s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
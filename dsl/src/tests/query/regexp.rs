@@ -0,0 +1,44 @@
+#[test]
+fn test_serialize() {
+    use crate::{clause, query::regexp::RegexpFlags};
+
+    let s = serde_json::to_string(&clause!(Regexp, "user.id", "k.*y")).unwrap();
+
+    assert_eq!(s, r#"{"regexp":{"user.id":{"value":"k.*y"}}}"#);
+
+    let s = serde_json::to_string(
+        &clause!(
+            Regexp,
+            "user.id",
+            "k.*y",
+            flags = RegexpFlags::COMPLEMENT | RegexpFlags::INTERVAL,
+            max_determinized_states = 20000,
+            case_insensitive = true
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"regexp":{"user.id":{"value":"k.*y","flags":"COMPLEMENT|INTERVAL","max_determinized_states":20000,"case_insensitive":true}}}"#
+    );
+
+    let s = serde_json::to_string(&clause!(Regexp, "user.id", "k.*y", flags = RegexpFlags::NONE)).unwrap();
+
+    assert_eq!(s, r#"{"regexp":{"user.id":{"value":"k.*y","flags":"NONE"}}}"#);
+}
+
+#[test]
+fn test_deserialize() {
+    use crate::query::Query;
+
+    let s = r#"{"regexp":{"user.id":{"value":"k.*y","flags":"ANYSTRING|EMPTY","rewrite":"constant_score","boost":1.5}}}"#;
+
+    let q: Query = serde_json::from_str(s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+
+    let q: Query = serde_json::from_str(r#"{"regexp":{"user.id":{"value":"k.*y","flags":"ALL","max_determinized_states":10000}}}"#).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), r#"{"regexp":{"user.id":{"value":"k.*y"}}}"#);
+}
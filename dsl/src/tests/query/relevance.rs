@@ -0,0 +1,131 @@
+#[test]
+fn test_rank_feature() {
+    use crate::query::{
+        rank_feature::{RankFeature, RankFeatureFunction},
+        Query,
+    };
+
+    for (rank_feature, s) in [
+        (RankFeature::new("pagerank"), r#"{"rank_feature":{"field":"pagerank"}}"#),
+        (
+            RankFeature::new("pagerank").function(RankFeatureFunction::Saturation { pivot: None }),
+            r#"{"rank_feature":{"field":"pagerank","saturation":{}}}"#,
+        ),
+        (
+            RankFeature::new("pagerank").function(RankFeatureFunction::Saturation { pivot: Some(8.0) }).boost(0.1),
+            r#"{"rank_feature":{"field":"pagerank","saturation":{"pivot":8.0},"boost":0.1}}"#,
+        ),
+        (
+            RankFeature::new("pagerank").function(RankFeatureFunction::Log { scaling_factor: 4.0 }),
+            r#"{"rank_feature":{"field":"pagerank","log":{"scaling_factor":4.0}}}"#,
+        ),
+        (
+            RankFeature::new("pagerank").function(RankFeatureFunction::Sigmoid { pivot: 7.0, exponent: 0.6 }),
+            r#"{"rank_feature":{"field":"pagerank","sigmoid":{"pivot":7.0,"exponent":0.6}}}"#,
+        ),
+        (
+            RankFeature::new("topics.sports").function(RankFeatureFunction::Linear {}),
+            r#"{"rank_feature":{"field":"topics.sports","linear":{}}}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&Query::RankFeature(rank_feature)).unwrap(), s);
+
+        let q: Query = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&q).unwrap(), s);
+    }
+}
+
+#[test]
+fn test_distance_feature() {
+    use crate::{
+        query::{distance_feature::DistanceFeature, Query},
+        types::{
+            geo::{Distance, DistanceUnit, GeoPoint},
+            time::{TimeUnit, TimeValue},
+        },
+    };
+
+    for (distance_feature, s) in [
+        (
+            DistanceFeature::date("production_date", "now", TimeValue::new(7, TimeUnit::Days)),
+            r#"{"distance_feature":{"field":"production_date","origin":"now","pivot":"7d"}}"#,
+        ),
+        (
+            DistanceFeature::geo("location", GeoPoint::array(40.71, -71.3), Distance::new(1, DistanceUnit::Kilometers))
+                .boost(2.0),
+            r#"{"distance_feature":{"field":"location","origin":[-71.3,40.71],"pivot":"1km","boost":2.0}}"#,
+        ),
+        (
+            DistanceFeature::geo("location", GeoPoint::geohash("drm3btev3e86"), Distance::new(2, DistanceUnit::Miles)),
+            r#"{"distance_feature":{"field":"location","origin":"drm3btev3e86","pivot":"2mi"}}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&Query::DistanceFeature(distance_feature)).unwrap(), s);
+
+        let q: Query = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&q).unwrap(), s);
+    }
+
+    // `m` is both minutes and meters
+    for (s, origin) in [
+        (r#"{"field":"location","origin":"40.71,-71.3","pivot":"1000m"}"#, "Geo {"),
+        (r#"{"field":"location","origin":{"lat":40.71,"lon":-71.3},"pivot":"1000m"}"#, "Geo {"),
+        (r#"{"field":"production_date","origin":"now-1h","pivot":"10m"}"#, "Date {"),
+    ] {
+        let distance_feature: DistanceFeature = serde_json::from_str(s).unwrap();
+
+        assert!(format!("{:?}", distance_feature).contains(origin));
+        assert_eq!(serde_json::to_string(&distance_feature).unwrap(), s);
+    }
+
+    assert!(
+        serde_json::from_str::<DistanceFeature>(r#"{"field":"location","origin":[-71.3,40.71],"pivot":"7d"}"#).is_err()
+    );
+}
+
+#[test]
+fn test_pinned() {
+    use crate::{
+        clause,
+        query::{
+            pinned::{Pinned, PinnedDoc},
+            Query,
+            QueryValue,
+        },
+    };
+
+    let s = serde_json::to_string(
+        &Query::Pinned(Pinned::ids(["1", "4", "100"], clause!(Match, "description", QueryValue::from("iphone"))))
+    )
+    .unwrap();
+
+    assert_eq!(s, r#"{"pinned":{"ids":["1","4","100"],"organic":{"match":{"description":{"query":"iphone"}}}}}"#);
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+
+    let s = serde_json::to_string(
+        &Query::Pinned(
+            Pinned::docs(
+                [PinnedDoc::new("my-index-000001", "1"), PinnedDoc::new("my-index-000002", "4")],
+                clause!(Match, "description", QueryValue::from("iphone"))
+            )
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"pinned":{"docs":[{"_index":"my-index-000001","_id":"1"},{"_index":"my-index-000002","_id":"4"}],"#,
+            r#""organic":{"match":{"description":{"query":"iphone"}}}}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
@@ -0,0 +1,87 @@
+#[test]
+fn test_script() {
+    use crate::{
+        query::{script::ScriptQuery, Query},
+        script::Script,
+    };
+
+    let s = serde_json::to_string(
+        &Query::Script(
+            ScriptQuery::new(
+                Script::new("doc['num1'].value > params.param1")
+                    .lang("painless")
+                    .param("param1", 5)
+                    .param("tags", vec!["a", "b"])
+            )
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"script":{"script":{"source":"doc['num1'].value > params.param1","lang":"painless","#,
+            r#""params":{"param1":5,"tags":["a","b"]}}}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+
+    let s = serde_json::to_string(
+        &Query::Script(ScriptQuery::new(Script::stored("my-filter").param("threshold", 1.5)).boost(2.0))
+    )
+    .unwrap();
+
+    assert_eq!(s, r#"{"script":{"script":{"id":"my-filter","params":{"threshold":1.5}},"boost":2.0}}"#);
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
+
+#[test]
+fn test_script_short_form() {
+    use crate::query::Query;
+
+    let q: Query = serde_json::from_str(r#"{"script":{"script":"doc['num1'].value > 1"}}"#).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&q).unwrap(),
+        r#"{"script":{"script":{"source":"doc['num1'].value > 1"}}}"#
+    );
+}
+
+#[test]
+fn test_script_score() {
+    use crate::{
+        clause,
+        query::{script_score::ScriptScore, Query, QueryValue},
+        script::Script,
+    };
+
+    let s = serde_json::to_string(
+        &Query::ScriptScore(
+            ScriptScore::new(
+                clause!(Match, "message", QueryValue::from("elasticsearch")),
+                Script::new("doc['my-int'].value / 10")
+            )
+            .min_score(1.0)
+            .boost(3.0)
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"script_score":{"query":{"match":{"message":{"query":"elasticsearch"}}},"#,
+            r#""script":{"source":"doc['my-int'].value / 10"},"min_score":1.0,"boost":3.0}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
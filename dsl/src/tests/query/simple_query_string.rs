@@ -0,0 +1,53 @@
+#[test]
+fn test_serialize() {
+    use crate::query::simple_query_string::{SimpleQueryString, SimpleQueryStringFlags};
+
+    let q = SimpleQueryString::new("\"fried eggs\" +(eggplant | potato) -frittata")
+        .field_boost("title", 5.0)
+        .field("body");
+
+    assert_eq!(
+        serde_json::to_string(&q).unwrap(),
+        r#"{"query":"\"fried eggs\" +(eggplant | potato) -frittata","fields":["title^5","body"]}"#
+    );
+
+    let q = q.flags(SimpleQueryStringFlags::OR | SimpleQueryStringFlags::AND | SimpleQueryStringFlags::PREFIX);
+
+    assert_eq!(
+        serde_json::to_string(&q).unwrap(),
+        r#"{"query":"\"fried eggs\" +(eggplant | potato) -frittata","fields":["title^5","body"],"flags":"AND|OR|PREFIX"}"#
+    );
+}
+
+#[test]
+fn test_flags() {
+    use crate::query::simple_query_string::SimpleQueryStringFlags;
+
+    assert_eq!(&SimpleQueryStringFlags::ALL.to_string(), "ALL");
+    assert_eq!(&SimpleQueryStringFlags::NONE.to_string(), "NONE");
+    assert_eq!(&(SimpleQueryStringFlags::FUZZY | SimpleQueryStringFlags::NEAR).to_string(), "FUZZY|NEAR");
+
+    assert_eq!(
+        "or|And | PREFIX".parse::<SimpleQueryStringFlags>(),
+        Ok(SimpleQueryStringFlags::OR | SimpleQueryStringFlags::AND | SimpleQueryStringFlags::PREFIX)
+    );
+    assert_eq!("NONE".parse::<SimpleQueryStringFlags>(), Ok(SimpleQueryStringFlags::NONE));
+    assert!("OR|XOR".parse::<SimpleQueryStringFlags>().is_err());
+
+    assert!(SimpleQueryStringFlags::ALL.contains(SimpleQueryStringFlags::WHITESPACE));
+    assert!(!SimpleQueryStringFlags::OR.contains(SimpleQueryStringFlags::AND));
+}
+
+#[test]
+fn test_deserialize() {
+    use crate::query::Query;
+
+    let s = r#"{"simple_query_string":{"query":"foo | bar","fields":["content"],"flags":"OR|NOT","lenient":true}}"#;
+
+    let q: Query = serde_json::from_str(s).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&q).unwrap(),
+        r#"{"simple_query_string":{"query":"foo | bar","fields":["content"],"flags":"NOT|OR","lenient":true}}"#
+    );
+}
@@ -0,0 +1,223 @@
+#[test]
+fn test_span_near() {
+    use crate::query::{
+        span::SpanQuery,
+        span_near::SpanNear,
+        span_term::SpanTerm,
+        Query,
+    };
+
+    let s = serde_json::to_string(
+        &Query::SpanNear(
+            SpanNear::new()
+                .clause(SpanQuery::SpanTerm(SpanTerm::new("field", "value1")))
+                .clause(SpanQuery::SpanTerm(SpanTerm::new("field", "value2").boost(2.0)))
+                .slop(12)
+                .in_order(false)
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"span_near":{"clauses":[{"span_term":{"field":{"value":"value1"}}},"#,
+            r#"{"span_term":{"field":{"value":"value2","boost":2.0}}}],"slop":12,"in_order":false}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+
+    let q: Query = serde_json::from_str(r#"{"span_near":{"clauses":[{"span_term":{"field":"value1"}}]}}"#).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&q).unwrap(),
+        r#"{"span_near":{"clauses":[{"span_term":{"field":{"value":"value1"}}}]}}"#
+    );
+}
+
+#[test]
+fn test_span_not() {
+    use crate::query::{
+        span::SpanQuery,
+        span_near::SpanNear,
+        span_not::SpanNot,
+        span_or::SpanOr,
+        span_term::SpanTerm,
+        Query,
+    };
+
+    let s = serde_json::to_string(
+        &Query::SpanNot(
+            SpanNot::new(
+                SpanQuery::SpanTerm(SpanTerm::new("field1", "hoya")),
+                SpanQuery::SpanNear(
+                    SpanNear::new()
+                        .clause(SpanQuery::SpanTerm(SpanTerm::new("field1", "la")))
+                        .clause(
+                            SpanQuery::SpanOr(
+                                SpanOr::new()
+                                    .clause(SpanQuery::SpanTerm(SpanTerm::new("field1", "hoya")))
+                            )
+                        )
+                        .in_order(true)
+                )
+            )
+            .pre(1)
+            .post(2)
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"span_not":{"include":{"span_term":{"field1":{"value":"hoya"}}},"#,
+            r#""exclude":{"span_near":{"clauses":[{"span_term":{"field1":{"value":"la"}}},"#,
+            r#"{"span_or":{"clauses":[{"span_term":{"field1":{"value":"hoya"}}}]}}],"in_order":true}},"#,
+            r#""pre":1,"post":2}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
+
+#[test]
+fn test_span_first_containing_within() {
+    use crate::query::{
+        span::SpanQuery,
+        span_containing::SpanContaining,
+        span_first::SpanFirst,
+        span_term::SpanTerm,
+        span_within::SpanWithin,
+        Query,
+    };
+
+    let s = serde_json::to_string(&Query::SpanFirst(SpanFirst::new(SpanQuery::SpanTerm(SpanTerm::new("user.id", "kimchy")), 3)))
+        .unwrap();
+
+    assert_eq!(s, r#"{"span_first":{"match":{"span_term":{"user.id":{"value":"kimchy"}}},"end":3}}"#);
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+
+    let s = serde_json::to_string(
+        &Query::SpanContaining(
+            SpanContaining::new(
+                SpanQuery::SpanTerm(SpanTerm::new("field1", "bar")),
+                SpanQuery::SpanTerm(SpanTerm::new("field1", "foo"))
+            )
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"span_containing":{"big":{"span_term":{"field1":{"value":"bar"}}},"little":{"span_term":{"field1":{"value":"foo"}}}}}"#
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+
+    let s = serde_json::to_string(
+        &Query::SpanWithin(
+            SpanWithin::new(
+                SpanQuery::SpanTerm(SpanTerm::new("field1", "bar")),
+                SpanQuery::SpanTerm(SpanTerm::new("field1", "foo"))
+            )
+            .boost(2.0)
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"span_within":{"big":{"span_term":{"field1":{"value":"bar"}}},"little":{"span_term":{"field1":{"value":"foo"}}},"boost":2.0}}"#
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
+
+#[test]
+fn test_span_multi() {
+    use crate::query::{
+        fuzzy::Fuzzy,
+        prefix::Prefix,
+        regexp::Regexp,
+        span_multi::{MultiTermQuery, SpanMulti},
+        wildcard::Wildcard,
+        Query,
+    };
+
+    for (r#match, s) in [
+        (
+            MultiTermQuery::Prefix(Prefix::new("user.id", "ki")),
+            r#"{"span_multi":{"match":{"prefix":{"user.id":{"value":"ki"}}}}}"#,
+        ),
+        (
+            MultiTermQuery::Wildcard(Wildcard::new("user.id", "ki*y", "ki*y")),
+            r#"{"span_multi":{"match":{"wildcard":{"user.id":{"value":"ki*y","wildcard":"ki*y"}}}}}"#,
+        ),
+        (
+            MultiTermQuery::Regexp(Regexp::new("user.id", "k.*y")),
+            r#"{"span_multi":{"match":{"regexp":{"user.id":{"value":"k.*y"}}}}}"#,
+        ),
+        (
+            MultiTermQuery::Fuzzy(Fuzzy::new("user.id", "ki")),
+            r#"{"span_multi":{"match":{"fuzzy":{"user.id":{"value":"ki"}}}}}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&Query::SpanMulti(SpanMulti::new(r#match))).unwrap(), s);
+
+        let q: Query = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&q).unwrap(), s);
+    }
+}
+
+#[test]
+fn test_field_masking_span() {
+    use crate::query::{
+        field_masking_span::FieldMaskingSpan,
+        span::SpanQuery,
+        span_near::SpanNear,
+        span_term::SpanTerm,
+        Query,
+    };
+
+    let q: Query = SpanQuery::SpanNear(
+        SpanNear::new()
+            .clause(SpanQuery::SpanTerm(SpanTerm::new("text", "quick brown")))
+            .clause(
+                SpanQuery::FieldMaskingSpan(
+                    FieldMaskingSpan::new(SpanQuery::SpanTerm(SpanTerm::new("text.stems", "fox")), "text")
+                )
+            )
+            .slop(5)
+            .in_order(false)
+    )
+    .into();
+
+    let s = serde_json::to_string(&q).unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"span_near":{"clauses":[{"span_term":{"text":{"value":"quick brown"}}},"#,
+            r#"{"field_masking_span":{"query":{"span_term":{"text.stems":{"value":"fox"}}},"field":"text"}}],"#,
+            r#""slop":5,"in_order":false}}"#,
+        )
+    );
+
+    let q: Query = serde_json::from_str(&s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
@@ -0,0 +1,31 @@
+#[test]
+fn test_serialize() {
+    use crate::{clause, query::term::Term};
+
+    let s = serde_json::to_string(&clause!(Term, "user.id", "kimchy", boost = 1.5)).unwrap();
+
+    assert_eq!(s, r#"{"term":{"user.id":{"value":"kimchy","boost":1.5}}}"#);
+
+    let s = serde_json::to_string(&Term::new("status", 200)).unwrap();
+
+    assert_eq!(s, r#"{"status":{"value":200}}"#);
+}
+
+#[test]
+fn test_deserialize() {
+    use crate::query::Query;
+
+    let q: Query = serde_json::from_str(r#"{"term":{"status":200}}"#).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), r#"{"term":{"status":{"value":200}}}"#);
+
+    let q: Query = serde_json::from_str(r#"{"term":{"active":true}}"#).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), r#"{"term":{"active":{"value":true}}}"#);
+
+    let s = r#"{"term":{"user.id":{"value":"kimchy","case_insensitive":true}}}"#;
+
+    let q: Query = serde_json::from_str(s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
@@ -0,0 +1,59 @@
+#[test]
+fn test_serialize() {
+    use crate::{
+        clause,
+        query::{
+            terms::{Terms, TermsLookup},
+            Query,
+        },
+        types::number::Number,
+    };
+
+    let s = serde_json::to_string(&clause!(Terms, "user.id", ["kimchy", "elkbee"])).unwrap();
+
+    assert_eq!(s, r#"{"terms":{"user.id":["kimchy","elkbee"]}}"#);
+
+    let s = serde_json::to_string(
+        &clause!(Terms, "http.response.status_code", [200, 404], boost = 2.0)
+    )
+    .unwrap();
+
+    assert_eq!(s, r#"{"terms":{"http.response.status_code":[200,404],"boost":2.0}}"#);
+
+    let s = serde_json::to_string(&Terms::new("price", [Number::F64(9.99), Number::U8(10)])).unwrap();
+
+    assert_eq!(s, r#"{"price":[9.99,10]}"#);
+
+    let s = serde_json::to_string(
+        &Query::Terms(
+            Terms::lookup(
+                "color",
+                TermsLookup::new("my-index-000001", "2", "color")
+                    .routing("shard-1")
+            )
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"terms":{"color":{"index":"my-index-000001","id":"2","path":"color","routing":"shard-1"}}}"#
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    use crate::query::Query;
+
+    for s in [
+        r#"{"terms":{"user.id":["kimchy",1,true],"boost":1.5}}"#,
+        r#"{"terms":{"color":{"index":"my-index-000001","id":"2","path":"color"}}}"#,
+    ] {
+        let q: Query = serde_json::from_str(s).unwrap();
+
+        assert_eq!(serde_json::to_string(&q).unwrap(), s);
+    }
+
+    assert!(serde_json::from_str::<Query>(r#"{"terms":{"boost":1.5}}"#).is_err());
+    assert!(serde_json::from_str::<Query>(r#"{"terms":{"a":["x"],"b":["y"]}}"#).is_err());
+}
@@ -0,0 +1,52 @@
+#[test]
+fn test_serialize() {
+    use crate::{
+        clause,
+        script::Script,
+    };
+
+    let s = serde_json::to_string(
+        &clause!(
+            TermsSet,
+            "programming_languages",
+            ["c++", "java"],
+            minimum_should_match_field = "required_matches"
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        r#"{"terms_set":{"programming_languages":{"terms":["c++","java"],"minimum_should_match_field":"required_matches"}}}"#
+    );
+
+    let s = serde_json::to_string(
+        &clause!(
+            TermsSet,
+            "programming_languages",
+            ["c++", "java", "php"],
+            minimum_should_match_script = Script::new("Math.min(params.num_terms, params.max)")
+                .param("max", 2)
+        )
+    )
+    .unwrap();
+
+    assert_eq!(
+        s,
+        concat!(
+            r#"{"terms_set":{"programming_languages":{"terms":["c++","java","php"],"#,
+            r#""minimum_should_match_script":{"source":"Math.min(params.num_terms, params.max)","params":{"max":2}}}}}"#,
+        )
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    use crate::query::Query;
+
+    let s = r#"{"terms_set":{"tags":{"terms":["a","b"],"minimum_should_match_script":{"source":"params.num_terms"},"boost":2.0}}}"#;
+
+    let q: Query = serde_json::from_str(s).unwrap();
+
+    assert_eq!(serde_json::to_string(&q).unwrap(), s);
+}
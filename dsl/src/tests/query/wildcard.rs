@@ -9,7 +9,7 @@ fn test_serialize() {
     assert_eq!(w0, r#"{"user.id":{"value":"ki*y","wildcard":"wildcard"}}"#);
 
     let w1 = serde_json::to_string(
-        w.clone()
+        &w.clone()
             .rewrite("rewrite")
     )
     .unwrap();
@@ -17,7 +17,7 @@ fn test_serialize() {
     assert_eq!(w1, r#"{"user.id":{"rewrite":"rewrite","value":"ki*y","wildcard":"wildcard"}}"#);
 
     let w2 = serde_json::to_string(
-        w.clone()
+        &w.clone()
             .boost(2.0)
     )
     .unwrap();
@@ -25,10 +25,31 @@ fn test_serialize() {
     assert_eq!(w2, r#"{"user.id":{"boost":2.0,"value":"ki*y","wildcard":"wildcard"}}"#);
 
     let w3 = serde_json::to_string(
-        w.clone()
+        &w.clone()
             .case_insensitive(true)
     )
     .unwrap();
 
     assert_eq!(w3, r#"{"user.id":{"case_insensitive":true,"value":"ki*y","wildcard":"wildcard"}}"#);
 }
+
+#[test]
+fn test_deserialize() {
+    use crate::query::wildcard::Wildcard;
+
+    let w: Wildcard = serde_json::from_str(r#"{"user.id":{"value":"ki*y","boost":2.0}}"#).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&w).unwrap(),
+        r#"{"user.id":{"boost":2.0,"value":"ki*y","wildcard":"ki*y"}}"#
+    );
+
+    let w: Wildcard = serde_json::from_str(r#"{"user.id":"ki*y"}"#).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&w).unwrap(),
+        r#"{"user.id":{"value":"ki*y","wildcard":"ki*y"}}"#
+    );
+
+    assert!(serde_json::from_str::<Wildcard>(r#"{"user.id":{"boost":2.0}}"#).is_err());
+}
@@ -0,0 +1,164 @@
+#[test]
+fn test_owned() {
+    use crate::{
+        clause,
+        query::{
+            bool::Bool,
+            Query,
+            QueryValue,
+        },
+        search::Search,
+        sort::{
+            Order,
+            Sort,
+        },
+        sort_clause,
+    };
+
+    fn assert_static<T: 'static>(_: &T) {}
+
+    fn build_search(field: &str, action: &str) -> Search {
+        Search::new()
+            .query(
+                Query::Bool(
+                    Bool::new()
+                        .must(
+                            clause!(
+                                Match,
+                                field.to_owned(),
+                                QueryValue::Text(action.to_owned())
+                            )
+                        )
+                )
+            )
+            .sort(
+                Sort::new()
+                    .sort(sort_clause!("@timestamp", order = Order::Desc))
+            )
+    }
+
+    let search = {
+        let field = String::from("event.action");
+        build_search(&field, "logged-in")
+    };
+
+    assert_static(&search);
+
+    assert_eq!(
+        serde_json::to_string(&search).unwrap(),
+        concat!(
+            r#"{"query":{"bool":{"must":[{"match":{"event.action":{"query":"logged-in"}}}]}},"#,
+            r#""sort":[{"@timestamp":{"order":"desc"}}]}"#,
+        )
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    use serde_json::json;
+    use crate::search::Search;
+
+    let v = json!({
+        "query": {
+            "bool": {
+                "must": [
+                    {"match": {"event.action": {"query": "logged-in", "operator": "AND"}}},
+                    {
+                        "nested": {
+                            "path": "parent",
+                            "query": {
+                                "bool": {
+                                    "filter": [{"range": {"parent.age": {"gte": 21, "lt": 65.5}}}],
+                                    "must_not": [{"exists": {"field": "parent.deleted_at"}}]
+                                }
+                            },
+                            "score_mode": "max"
+                        }
+                    }
+                ],
+                "filter": [
+                    {"range": {"@timestamp": {"gte": "now-1h/H", "lte": "now/H", "relation": "WITHIN"}}},
+                    {"term": {"user.id": {"value": "kimchy", "boost": 2.0}}},
+                    {"wildcard": {"host.name": {"value": "web-*", "wildcard": "web-*"}}},
+                    {"match_all": {}}
+                ]
+            }
+        },
+        "sort": [
+            {
+                "@timestamp": {
+                    "order": "desc",
+                    "mode": "max",
+                    "nested": {
+                        "path": "parent",
+                        "filter": {"term": {"parent.active": {"value": "true"}}},
+                        "max_children": 3
+                    }
+                }
+            }
+        ]
+    });
+
+    let search: Search = serde_json::from_value(v.clone()).unwrap();
+
+    assert_eq!(search.build(), v);
+}
+
+#[test]
+fn test_deserialize_short_forms() {
+    use serde_json::json;
+    use crate::search::Search;
+
+    let search: Search = serde_json::from_value(json!({
+        "query": {
+            "bool": {
+                "must": {"match": {"event.action": "logged-in"}},
+                "filter": {"term": {"user.id": "kimchy"}}
+            }
+        },
+        "sort": "@timestamp"
+    }))
+    .unwrap();
+
+    assert_eq!(
+        search.build(),
+        json!({
+            "query": {
+                "bool": {
+                    "must": [{"match": {"event.action": {"query": "logged-in"}}}],
+                    "filter": [{"term": {"user.id": {"value": "kimchy"}}}]
+                }
+            },
+            "sort": [{"@timestamp": {}}]
+        })
+    );
+}
+
+#[test]
+fn test_paging_and_source() {
+    use crate::search::Search;
+
+    let s = r#"{"from":20,"size":10,"_source":["user.id","@timestamp"]}"#;
+
+    let search = Search::new()
+        .from(20)
+        .size(10)
+        .source_includes(["user.id", "@timestamp"]);
+
+    assert_eq!(serde_json::to_string(&search).unwrap(), s);
+
+    let search: Search = serde_json::from_str(s).unwrap();
+
+    assert_eq!(serde_json::to_string(&search).unwrap(), s);
+
+    let search: Search = serde_json::from_str(r#"{"_source":false}"#).unwrap();
+
+    assert_eq!(serde_json::to_string(&search).unwrap(), r#"{"_source":false}"#);
+}
+
+#[test]
+fn test_deserialize_unknown_field() {
+    use crate::search::Search;
+
+    assert!(serde_json::from_str::<Search>(r#"{"size":10,"track_total_hitz":true}"#).is_err());
+}
@@ -0,0 +1,23 @@
+#[test]
+fn test_send_sync() {
+    use crate::{
+        aggregation::{Aggregation, Aggregations},
+        query::Query,
+        search::Search,
+        sort::{
+            nested::SortNested,
+            Sort,
+            SortClause,
+        },
+    };
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Search>();
+    assert_send_sync::<Query>();
+    assert_send_sync::<Sort>();
+    assert_send_sync::<SortClause>();
+    assert_send_sync::<SortNested>();
+    assert_send_sync::<Aggregation>();
+    assert_send_sync::<Aggregations>();
+}
@@ -1,3 +1,4 @@
+mod test_deserialize;
 mod test_order;
 mod test_mode;
 mod test_numberic_type;
@@ -0,0 +1,24 @@
+#[test]
+fn test_deserialize() {
+    use serde_json::json;
+    use crate::sort::Sort;
+
+    let sort: Sort = serde_json::from_value(json!([
+        "_score",
+        {"@timestamp": "desc"},
+        {"price": {"order": "asc", "mode": "avg", "numberic_type": "double"}}
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&sort).unwrap(),
+        json!([
+            {"_score": {}},
+            {"@timestamp": {"order": "desc"}},
+            {"price": {"order": "asc", "mode": "avg", "numberic_type": "double"}}
+        ])
+    );
+
+    assert!(serde_json::from_value::<Sort>(json!({"price": "sideways"})).is_err());
+    assert!(serde_json::from_value::<Sort>(json!({"price": "asc", "name": "desc"})).is_err());
+}
@@ -0,0 +1,94 @@
+/// Declare a typed set of flags serialized as `FLAG_A|FLAG_B`
+///
+/// The declared type gets an associated constant per flag, plus `ALL` and
+/// `NONE`, and can be combined with `|`. It serializes as `ALL` when every
+/// flag is set and as `NONE` when none is set, and parses flag names
+/// ignoring ASCII case.
+macro_rules! declare_flags {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($flag:ident = $bit:expr,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, serde_with::SerializeDisplay, serde_with::DeserializeFromStr)]
+        pub struct $name(u32);
+
+        #[allow(dead_code)]
+        impl $name {
+            $(pub const $flag: Self = Self(1 << $bit);)*
+
+            pub const ALL: Self = Self(0 $(| (1 << $bit))*);
+
+            pub const NONE: Self = Self(0);
+
+            pub fn contains(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::ALL
+            }
+        }
+
+        impl $crate::types::EqualsToDefault for $name {}
+
+        impl std::ops::BitOr for $name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl std::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                if *self == Self::ALL {
+                    return write!(f, "ALL");
+                }
+
+                if *self == Self::NONE {
+                    return write!(f, "NONE");
+                }
+
+                let names: Vec<&str> = [$((Self::$flag, stringify!($flag)),)*]
+                    .into_iter()
+                    .filter(|(flag, _)| self.contains(*flag))
+                    .map(|(_, name)| name)
+                    .collect();
+
+                write!(f, "{}", names.join("|"))
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut flags = Self::NONE;
+
+                for name in s.split('|').map(str::trim) {
+                    flags |= match name.to_ascii_uppercase().as_str() {
+                        "ALL" => Self::ALL,
+                        "NONE" => Self::NONE,
+                        $(stringify!($flag) => Self::$flag,)*
+                        _ => return Err(format!("unknown flag `{}`", name)),
+                    };
+                }
+
+                Ok(flags)
+            }
+        }
+    };
+}
+
+pub(crate) use declare_flags;
@@ -0,0 +1,261 @@
+use std::borrow::Cow;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use macros::{DisplayCase, FromStrCase};
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+    ser::{SerializeMap, SerializeSeq},
+};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+use super::EqualsToDefault;
+
+/// A geo point, in any of the formats accepted by Elasticsearch
+///
+/// Reference: [Geo-point field type](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/geo-point.html)
+///
+/// # Example
+/// ```
+/// use dsl::types::geo::GeoPoint;
+///
+/// assert_eq!(serde_json::to_string(&GeoPoint::lat_lon(41.12, -71.34)).unwrap(), r#"{"lat":41.12,"lon":-71.34}"#);
+/// assert_eq!(serde_json::to_string(&GeoPoint::array(41.12, -71.34)).unwrap(), r#"[-71.34,41.12]"#);
+/// assert_eq!(serde_json::to_string(&GeoPoint::string(41.12, -71.34)).unwrap(), r#""41.12,-71.34""#);
+/// assert_eq!(serde_json::to_string(&GeoPoint::geohash("drm3btev3e86")).unwrap(), r#""drm3btev3e86""#);
+/// assert_eq!(serde_json::to_string(&GeoPoint::wkt(41.12, -71.34)).unwrap(), r#""POINT (-71.34 41.12)""#);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeoPoint {
+    /// `{"lat": 41.12, "lon": -71.34}`
+    Object { lat: f64, lon: f64 },
+    /// `[-71.34, 41.12]`, note the GeoJSON order, longitude first
+    Array { lat: f64, lon: f64 },
+    /// `"41.12,-71.34"`
+    String { lat: f64, lon: f64 },
+    /// `"drm3btev3e86"`
+    Geohash(Cow<'static, str>),
+    /// `"POINT (-71.34 41.12)"`
+    Wkt { lat: f64, lon: f64 },
+}
+
+impl GeoPoint {
+    pub fn lat_lon(lat: f64, lon: f64) -> Self {
+        Self::Object { lat, lon }
+    }
+
+    pub fn array(lat: f64, lon: f64) -> Self {
+        Self::Array { lat, lon }
+    }
+
+    pub fn string(lat: f64, lon: f64) -> Self {
+        Self::String { lat, lon }
+    }
+
+    pub fn geohash(v: impl Into<Cow<'static, str>>) -> Self {
+        Self::Geohash(v.into())
+    }
+
+    pub fn wkt(lat: f64, lon: f64) -> Self {
+        Self::Wkt { lat, lon }
+    }
+}
+
+impl Serialize for GeoPoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        match self {
+            Self::Object { lat, lon } => {
+                let mut m = serializer.serialize_map(Some(2))?;
+                m.serialize_entry("lat", lat)?;
+                m.serialize_entry("lon", lon)?;
+                m.end()
+            },
+            Self::Array { lat, lon } => {
+                let mut seq = serializer.serialize_seq(Some(2))?;
+                seq.serialize_element(lon)?;
+                seq.serialize_element(lat)?;
+                seq.end()
+            },
+            Self::String { lat, lon } => serializer.collect_str(&format_args!("{},{}", lat, lon)),
+            Self::Geohash(v) => serializer.serialize_str(v),
+            Self::Wkt { lat, lon } => serializer.collect_str(&format_args!("POINT ({} {})", lon, lat)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GeoPoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        struct GeoPointVisitor;
+
+        impl<'de> Visitor<'de> for GeoPointVisitor {
+            type Value = GeoPoint;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a geo point as an object, an array, a string, a geohash or a WKT point")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lat = None;
+                let mut lon = None;
+
+                while let Some(key) = map.next_key::<Cow<'de, str>>()? {
+                    match key.as_ref() {
+                        "lat" => lat = Some(map.next_value()?),
+                        "lon" => lon = Some(map.next_value()?),
+                        other => return Err(de::Error::unknown_field(other, &["lat", "lon"])),
+                    }
+                }
+
+                Ok(GeoPoint::Object {
+                    lat: lat.ok_or_else(|| de::Error::missing_field("lat"))?,
+                    lon: lon.ok_or_else(|| de::Error::missing_field("lon"))?,
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lon = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let lat = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+                Ok(GeoPoint::Array { lat, lon })
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                let invalid = || de::Error::invalid_value(de::Unexpected::Str(v), &self);
+
+                if let Some(coordinates) = v.trim().strip_prefix("POINT") {
+                    let coordinates = coordinates.trim().strip_prefix('(')
+                        .and_then(|c| c.strip_suffix(')'))
+                        .ok_or_else(invalid)?;
+                    let mut coordinates = coordinates.split_whitespace().map(f64::from_str);
+
+                    return match (coordinates.next(), coordinates.next(), coordinates.next()) {
+                        (Some(Ok(lon)), Some(Ok(lat)), None) => Ok(GeoPoint::Wkt { lat, lon }),
+                        _ => Err(invalid()),
+                    };
+                }
+
+                match v.split_once(',') {
+                    Some((lat, lon)) => Ok(GeoPoint::String {
+                        lat: lat.trim().parse().map_err(|_| invalid())?,
+                        lon: lon.trim().parse().map_err(|_| invalid())?,
+                    }),
+                    None => Ok(GeoPoint::Geohash(v.to_owned().into())),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(GeoPointVisitor)
+    }
+}
+
+/// A distance with its unit, serialized as e.g. `"12km"`
+///
+/// Reference: [Distance Units](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/api-conventions.html#distance-units)
+///
+/// # Example
+/// ```
+/// use dsl::types::geo::{Distance, DistanceUnit};
+///
+/// assert_eq!(Distance::new(12, DistanceUnit::Kilometers).to_string(), "12km");
+/// assert_eq!("1.5mi".parse::<Distance>().unwrap(), Distance::new(1.5, DistanceUnit::Miles));
+/// ```
+#[derive(Clone, Debug, PartialEq, SerializeDisplay, DeserializeFromStr)]
+pub struct Distance {
+    value: f64,
+    unit: DistanceUnit,
+}
+
+impl Distance {
+    pub fn new(value: impl Into<f64>, unit: DistanceUnit) -> Self {
+        Self {
+            value: value.into(),
+            unit,
+        }
+    }
+}
+
+impl Display for Distance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit)
+    }
+}
+
+impl FromStr for Distance {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(s.len());
+        let (value, unit) = s.split_at(split);
+
+        Ok(Self {
+            value: value.trim().parse().map_err(|_| format!("invalid distance `{}`", s))?,
+            unit: if unit.is_empty() { DistanceUnit::default() } else { unit.parse()? },
+        })
+    }
+}
+
+/// Defaults to meters, the unit of a distance given without one
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
+#[display_case(case = "lowercase")]
+pub enum DistanceUnit {
+    #[display_case(display_as = "mi")]
+    Miles,
+    #[display_case(display_as = "yd")]
+    Yards,
+    #[display_case(display_as = "ft")]
+    Feet,
+    #[display_case(display_as = "in")]
+    Inches,
+    #[display_case(display_as = "km")]
+    Kilometers,
+    #[display_case(display_as = "m")]
+    Meters,
+    #[display_case(display_as = "cm")]
+    Centimeters,
+    #[display_case(display_as = "mm")]
+    Millimeters,
+    #[display_case(display_as = "nmi")]
+    NauticalMiles,
+}
+
+impl Default for DistanceUnit {
+    fn default() -> Self {
+        Self::Meters
+    }
+}
+
+/// How the geo queries handle invalid coordinates, defaults to `STRICT`
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
+#[display_case(case = "uppercase")]
+pub enum ValidationMethod {
+    Strict,
+    #[display_case(display_as = "IGNORE_MALFORMED")]
+    IgnoreMalformed,
+    Coerce,
+}
+
+impl Default for ValidationMethod {
+    fn default() -> Self {
+        Self::Strict
+    }
+}
+
+impl EqualsToDefault for ValidationMethod {}
@@ -1,4 +1,7 @@
+pub(crate) mod flags;
+pub mod geo;
 pub mod number;
+pub mod time;
 
 pub(crate) trait EqualsToDefault
 where
@@ -0,0 +1,71 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use macros::{DisplayCase, FromStrCase};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+/// A duration with its unit, serialized as e.g. `"7d"`
+///
+/// Reference: [Time units](https://www.elastic.co/guide/en/elasticsearch/reference/7.14/api-conventions.html#time-units)
+///
+/// # Example
+/// ```
+/// use dsl::types::time::{TimeUnit, TimeValue};
+///
+/// assert_eq!(TimeValue::new(7, TimeUnit::Days).to_string(), "7d");
+/// assert_eq!("90s".parse::<TimeValue>().unwrap(), TimeValue::new(90, TimeUnit::Seconds));
+/// ```
+#[derive(Clone, Debug, PartialEq, SerializeDisplay, DeserializeFromStr)]
+pub struct TimeValue {
+    value: u64,
+    unit: TimeUnit,
+}
+
+impl TimeValue {
+    pub fn new(value: u64, unit: TimeUnit) -> Self {
+        Self {
+            value,
+            unit,
+        }
+    }
+}
+
+impl Display for TimeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit)
+    }
+}
+
+impl FromStr for TimeValue {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (value, unit) = s.split_at(split);
+
+        Ok(Self {
+            value: value.parse().map_err(|_| format!("invalid time value `{}`", s))?,
+            unit: unit.trim().parse()?,
+        })
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, DisplayCase, FromStrCase, SerializeDisplay, DeserializeFromStr)]
+#[display_case(case = "lowercase")]
+pub enum TimeUnit {
+    #[display_case(display_as = "d")]
+    Days,
+    #[display_case(display_as = "h")]
+    Hours,
+    #[display_case(display_as = "m")]
+    Minutes,
+    #[display_case(display_as = "s")]
+    Seconds,
+    #[display_case(display_as = "ms")]
+    Milliseconds,
+    #[display_case(display_as = "micros")]
+    Microseconds,
+    #[display_case(display_as = "nanos")]
+    Nanoseconds,
+}
@@ -9,6 +9,7 @@ use syn::{
     DataEnum,
     DeriveInput,
     Error,
+    Ident,
     Variant,
 };
 use case::parse::{
@@ -23,7 +24,7 @@ use case::parse::{
 /// This macro supports the following attribute:
 /// 
 /// - `#[display_case(case = "snakecase")]`: Specifies the case conversion for the
-/// enum variants. Supported values are `"lowercase"`, `"uppercase"`, and `"snakecase"`.
+///   enum variants. Supported values are `"lowercase"`, `"uppercase"`, and `"snakecase"`.
 /// 
 /// # Examples
 /// 
@@ -48,34 +49,15 @@ pub fn derive_display_case(input: TokenStream) -> TokenStream {
 
     let name = &input.ident;
 
-    let variants = match input.data {
-        Data::Enum(DataEnum { variants, .. }) => variants,
-        _ => return TokenStream::from(Error::new_spanned(&input, "DisplayCase can only be used with enums").to_compile_error())
-    };
-
-    let case = match parse_case_attribute(&input.attrs) {
-        Ok(case) => case,
+    let display_values = match parse_display_values(&input, "DisplayCase") {
+        Ok(display_values) => display_values,
         Err(e) => return TokenStream::from(e.to_compile_error())
     };
 
-    let pairs = match parse_variant_attribute(&variants) {
-        Ok(pairs) => pairs,
-        Err(e) => return TokenStream::from(e.to_compile_error())
-    };
-
-    let arms = variants.iter()
-        .map(|Variant { ident, .. }| {
-            match pairs.get(&ident) {
-                Some(display_as) => quote! {
-                    #name::#ident => write!(f, "{}", #display_as),
-                },
-                None => {
-                    let display_val = case.parse_str(&ident.to_string());
-
-                    quote! {
-                        #name::#ident => write!(f, "{}", #display_val),
-                    }
-                }
+    let arms = display_values.iter()
+        .map(|(ident, display_val)| {
+            quote! {
+                #name::#ident => write!(f, "{}", #display_val),
             }
         });
 
@@ -92,3 +74,90 @@ pub fn derive_display_case(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// The `FromStrCase` macro implements the `std::str::FromStr` trait for enum types,
+/// as the inverse of `DisplayCase`: each variant is parsed from the value it
+/// displays as. Matching ignores ASCII case, like Elasticsearch does when it
+/// parses enum-like parameters.
+/// 
+/// It reads the same `#[display_case(...)]` attributes as `DisplayCase`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use macros::{DisplayCase, FromStrCase};
+/// 
+/// #[derive(Debug, PartialEq, DisplayCase, FromStrCase)]
+/// #[display_case(case = "snakecase")]
+/// enum MyEnum {
+///     FirstVariant,
+///     #[display_case(display_as = "second")] // custom display value
+///     SecondVariant,
+/// }
+/// 
+/// assert_eq!("first_variant".parse::<MyEnum>(), Ok(MyEnum::FirstVariant));
+/// assert_eq!("SECOND".parse::<MyEnum>(), Ok(MyEnum::SecondVariant));
+/// assert!("third".parse::<MyEnum>().is_err());
+/// ```
+#[proc_macro_derive(FromStrCase, attributes(display_case))]
+pub fn derive_from_str_case(input: TokenStream) -> TokenStream {
+    // Parse to syntax tree
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = &input.ident;
+
+    let display_values = match parse_display_values(&input, "FromStrCase") {
+        Ok(display_values) => display_values,
+        Err(e) => return TokenStream::from(e.to_compile_error())
+    };
+
+    let arms = display_values.iter()
+        .map(|(ident, display_val)| {
+            quote! {
+                if s.eq_ignore_ascii_case(#display_val) {
+                    return Ok(#name::#ident);
+                }
+            }
+        });
+
+    // Build the output
+    let expanded = quote! {
+        impl std::str::FromStr for #name {
+            type Err = std::string::String;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                #(#arms)*
+
+                Err(format!("unknown variant `{}`", s))
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Resolve the displayed value of every variant, honoring the enum level `case`
+/// and the per variant `display_as` attributes.
+fn parse_display_values(input: &DeriveInput, derive_name: &str) -> syn::Result<Vec<(Ident, String)>> {
+    let variants = match &input.data {
+        Data::Enum(DataEnum { variants, .. }) => variants,
+        _ => return Err(Error::new_spanned(input, format!("{} can only be used with enums", derive_name)))
+    };
+
+    let case = parse_case_attribute(&input.attrs)?;
+
+    let pairs = parse_variant_attribute(variants)?;
+
+    let display_values = variants.iter()
+        .map(|Variant { ident, .. }| {
+            let display_val = match pairs.get(ident) {
+                Some(display_as) => display_as.clone(),
+                None => case.parse_str(&ident.to_string()),
+            };
+
+            (ident.clone(), display_val)
+        })
+        .collect();
+
+    Ok(display_values)
+}
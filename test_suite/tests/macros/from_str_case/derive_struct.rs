@@ -0,0 +1,10 @@
+use macros::FromStrCase;
+
+#[derive(FromStrCase)]
+#[display_case(case = "lowercase")]
+struct Test {
+    foo: String,
+    bar: i32,
+}
+
+fn main() {}